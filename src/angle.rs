@@ -1,18 +1,45 @@
-use core::f64::consts as f64_consts;
+use core::{
+    cmp::Ordering,
+    f64::consts as f64_consts,
+    num::FpCategory,
+    ops::{Add, Mul, Neg, Sub},
+};
 
 use bitvec::{bitarr, field::BitField as _, order::Msb0};
 
-use crate::{consts, repr::Repr, trig};
+use crate::{
+    consts, direction::Direction, repr::Repr, round::RoundMode, sign::Sign, signed::SignedAngle,
+    trig,
+};
 
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
 #[repr(transparent)]
 pub struct Angle(Repr);
 
+/// Which of the two bisectors of a pair of angles to select.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BisectorSide {
+    /// The bisector of the shorter arc between the two angles.
+    Internal,
+
+    /// The bisector of the longer arc between the two angles; this is always
+    /// a half turn away from the internal bisector.
+    External,
+}
+
 impl Angle {
+    /// The zero angle.
+    pub const ZERO: Angle = Angle::from_repr(0);
+
+    /// A quarter turn (90 degrees).
+    pub const QUARTER_TURN: Angle = Angle::from_repr(1 << 62);
+
+    /// A half turn (180 degrees).
+    pub const HALF_TURN: Angle = Angle::from_repr(1 << 63);
+
     /// Create a new float from the representational format. The representation
     /// here is a fractional value in the range [0, 1), filling the full space
     /// of a u64.
-
     #[inline]
     #[must_use]
     pub const fn from_repr(repr: u64) -> Self {
@@ -20,13 +47,96 @@ impl Angle {
     }
 
     /// Create a new float from a fractional number of rotations.
-
     #[inline]
     #[must_use]
     pub fn from_rotations(rotations: f64) -> Option<Self> {
         Repr::from_float(rotations).map(Self)
     }
 
+    /// Create a new angle from an `f32` fractional number of rotations, but
+    /// only if the conversion is lossless: that is, if converting the angle
+    /// back to an `f32` number of rotations reproduces `rotations` exactly.
+    /// Returns `None` if any precision would be lost, including if the value
+    /// is outside of [0, 1) and would be wrapped, or too small to represent.
+    #[must_use]
+    pub fn from_rotations_f32_exact(rotations: f32) -> Option<Self> {
+        let angle = Self::from_rotations(f64::from(rotations))?;
+
+        match angle.as_rotations() as f32 == rotations {
+            true => Some(angle),
+            false => None,
+        }
+    }
+
+    /// Create a new angle from a fractional number of rotations, as with
+    /// [`from_rotations`][Angle::from_rotations], but rounding according to
+    /// `mode` rather than always truncating. Floats with a magnitude of at
+    /// least `2^-11` are always converted exactly, so this only affects
+    /// inputs very close to zero.
+    #[inline]
+    #[must_use]
+    pub fn from_rotations_with(rotations: f64, mode: RoundMode) -> Option<Self> {
+        Repr::from_float_with(rotations, mode).map(Self)
+    }
+
+    /// Create a new angle that is exactly `numerator / denominator` of a
+    /// turn, using only integer arithmetic. Whole turns are discarded, and
+    /// fractions that can't be represented exactly are truncated towards
+    /// zero. Returns `None` if the denominator is zero.
+    #[inline]
+    #[must_use]
+    pub const fn from_ratio(numerator: u64, denominator: u64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let numerator = (numerator % denominator) as u128;
+        let repr = (numerator << 64) / denominator as u128;
+
+        Some(Self::from_repr(repr as u64))
+    }
+
+    /// Create a new angle from a whole number of degrees, using only integer
+    /// arithmetic. This is exactly `from_ratio(degrees, 360)`, after reducing
+    /// `degrees` into the range `0..360`, so (unlike `from_degrees`) it
+    /// doesn't suffer any float rounding.
+    #[inline]
+    #[must_use]
+    pub const fn from_degrees_exact_int(degrees: i64) -> Self {
+        let degrees = degrees.rem_euclid(360) as u64;
+
+        match Self::from_ratio(degrees, 360) {
+            Some(angle) => angle,
+            None => unreachable!(),
+        }
+    }
+
+    /// Get the interior angle of a regular polygon with `sides` sides, which
+    /// is `(sides - 2) / sides` of a half turn. This is computed exactly with
+    /// `from_ratio`. Returns `None` if there are fewer than 3 sides.
+    #[inline]
+    #[must_use]
+    pub const fn interior_angle(sides: u32) -> Option<Self> {
+        if sides < 3 {
+            return None;
+        }
+
+        Self::from_ratio(sides as u64 - 2, sides as u64 * 2)
+    }
+
+    /// Get the exterior angle of a regular polygon with `sides` sides, which
+    /// is `1 / sides` of a turn; this is the supplement of the interior
+    /// angle. Returns `None` if there are fewer than 3 sides.
+    #[inline]
+    #[must_use]
+    pub const fn exterior_angle(sides: u32) -> Option<Self> {
+        if sides < 3 {
+            return None;
+        }
+
+        Self::from_ratio(1, sides as u64)
+    }
+
     #[inline]
     #[must_use]
     pub fn from_radians(radians: f64) -> Option<Self> {
@@ -38,20 +148,478 @@ impl Angle {
         Self::from_rotations(radians / f64_consts::TAU)
     }
 
+    /// Create a new angle from radians, dividing by TAU in extended fixed
+    /// point rather than in floating point. Large inputs (many turns) lose
+    /// precision when they're divided as floats; this instead multiplies the
+    /// exact value of the float by a 256-bit approximation of `1 / TAU`, in
+    /// the style of the Payne-Hanek range reduction.
+    ///
+    /// For any input of magnitude less than 2^181, the result is less than 2
+    /// units of the repr (`2^-63` turns) away from the exact reduction of the
+    /// input modulo TAU. Returns `None` if the input is larger than that, or
+    /// isn't finite.
+    #[must_use]
+    pub fn from_radians_precise(radians: f64) -> Option<Self> {
+        match radians.classify() {
+            FpCategory::Zero => return Some(Self::from_repr(0)),
+            FpCategory::Nan | FpCategory::Infinite => return None,
+            FpCategory::Normal | FpCategory::Subnormal => {}
+        }
+
+        // Decompose the float into an exact `mantissa * 2^exponent`
+        let bits = radians.to_bits();
+        let raw_exponent = ((bits >> 52) & 0x07_FF) as i32;
+        let mantissa = (bits & (u64::MAX >> 12)) | (((raw_exponent != 0) as u64) << 52);
+        let exponent = raw_exponent.max(1) - 1075;
+
+        if exponent > 128 {
+            return None;
+        }
+
+        // Compute the 320 bit product `mantissa * INV_TAU`, in little-endian
+        // limbs. This represents `radians / TAU` scaled by `2^(256 - exponent)`.
+        let mut product = [0u64; 5];
+        let mut carry = 0u128;
+
+        for (limb, &inv_tau) in product.iter_mut().zip(consts::INV_TAU.iter().rev()) {
+            let wide = inv_tau as u128 * mantissa as u128 + carry;
+            *limb = wide as u64;
+            carry = wide >> 64;
+        }
+        product[4] = carry as u64;
+
+        // The whole turns are in the bits above `256 - exponent`; the 64 bits
+        // below them are the repr. Everything below that is truncated.
+        let shift = (192 - exponent) as usize;
+        let low = product.get(shift / 64).copied().unwrap_or(0) as u128;
+        let high = product.get(shift / 64 + 1).copied().unwrap_or(0) as u128;
+        let repr = (((high << 64) | low) >> (shift % 64)) as u64;
+
+        Some(Self::from_repr(match radians.is_sign_negative() {
+            false => repr,
+            true => repr.wrapping_neg(),
+        }))
+    }
+
+    /// Create a new angle from a phase that's been split into a whole number
+    /// of turns and a remainder in radians, as tracked by a long-running
+    /// phase accumulator. Whole turns don't affect the angle, so
+    /// `whole_turns` is ignored entirely (it isn't validated or used); it's
+    /// accepted only so that a split phase can be passed as-is. In
+    /// particular, it's never combined with the (much smaller) remainder in
+    /// floating point, which would lose precision.
+    #[inline]
+    #[must_use]
+    pub fn from_radians_turns_split(_whole_turns: i64, fractional_radians: f64) -> Option<Self> {
+        Self::from_radians(fractional_radians)
+    }
+
     #[inline]
     #[must_use]
     pub fn from_degrees(degrees: f64) -> Option<Self> {
         Self::from_rotations(degrees / 360.0)
     }
 
-    /// Get a lossless representation of this angle as an unsigned integer.
+    /// Create a new angle from a value in an arbitrary unit, where `full` is
+    /// the number of units in a full turn; for instance, a dial marked `0..50`
+    /// has a `full` of 50. This is `from_rotations(value / full)`, so it
+    /// returns `None` if `full` is zero or either value isn't finite.
+    #[inline]
+    #[must_use]
+    pub fn from_scale(value: f64, full: f64) -> Option<Self> {
+        Self::from_rotations(value / full)
+    }
+
+    /// Create a new angle from degrees, as with
+    /// [`from_degrees`][Angle::from_degrees], and also report the residual:
+    /// the requested number of degrees minus the degrees of the angle that
+    /// was actually constructed, ignoring whole turns. This quantifies the
+    /// quantization error of the construction, and is computed exactly
+    /// (before the final rounding to `f64`), so it's nonzero even when the
+    /// error is far too small to show up in `as_degrees`.
+    #[must_use]
+    pub fn from_degrees_report(degrees: f64) -> Option<(Self, f64)> {
+        let angle = Self::from_degrees(degrees)?;
+
+        // This reduction is exact, and leaves a value smaller than 2^9
+        let wrapped = libm::fmod(degrees, 360.0);
+        let (fraction, exponent) = libm::frexp(wrapped.abs());
+        let mantissa = (fraction * (1u64 << 53) as f64) as u128;
+
+        // Scale the requested value to units of the repr, and split it into
+        // whole and fractional units. The value is `mantissa * 2^shift / 360`
+        // units; for small values, the scale goes into the denominator, so
+        // none of the mantissa is lost.
+        let shift = exponent + 11;
+        let (whole, fraction) = match shift {
+            0.. => {
+                let scaled = mantissa << shift;
+                ((scaled / 360) as u64, (scaled % 360) as f64 / 360.0)
+            }
+            -64..=-1 => {
+                let denominator = 360u128 << -shift;
+                let whole = (mantissa / denominator) as u64;
+                let remainder = mantissa % denominator;
+
+                (whole, remainder as f64 / denominator as f64)
+            }
+            // The mantissa is less than 2^53, so when the denominator
+            // doesn't fit in a u128, it's larger than the mantissa and there
+            // are no whole units.
+            _ => (0, libm::ldexp(mantissa as f64 / 360.0, shift)),
+        };
+
+        let (whole, fraction) = match wrapped < 0.0 {
+            false => (whole, fraction),
+            true => (whole.wrapping_neg(), -fraction),
+        };
+
+        let residual = whole.wrapping_sub(angle.repr()) as i64 as f64 + fraction;
+        Some((angle, residual * 360.0 / (1u128 << 64) as f64))
+    }
+
+    /// Create a new angle from degrees, as with
+    /// [`from_degrees`][Angle::from_degrees], and also report whether the
+    /// input was outside of the range [0, 360) and therefore had to be
+    /// reduced into it.
+    #[inline]
+    #[must_use]
+    pub fn from_degrees_checked(degrees: f64) -> Option<(Self, bool)> {
+        let wrapped = !(0.0..360.0).contains(&degrees);
+        Self::from_degrees(degrees).map(|angle| (angle, wrapped))
+    }
+
+    /// Create a new angle from degrees and decimal minutes (the "DMM" format
+    /// commonly produced by GPS devices, such as `40° 26.767'`). The sign of
+    /// `degrees` applies to the whole angle, so `(-40, 30.0)` is -40.5
+    /// degrees.
+    #[inline]
+    #[must_use]
+    pub fn from_degrees_minutes(degrees: i32, minutes: f64) -> Option<Self> {
+        let degrees = f64::from(degrees);
+        let minutes = minutes / 60.0;
+
+        Self::from_degrees(match degrees < 0.0 {
+            true => degrees - minutes,
+            false => degrees + minutes,
+        })
+    }
+
+    /// Create a new angle from an unsigned degrees, minutes, and seconds
+    /// value, along with a separate sign, as is common for latitudes and
+    /// longitudes (where the sign is the hemisphere). The magnitude is
+    /// converted first, and then negated if the sign is `Negative`. Returns
+    /// `None` if `seconds` isn't finite.
+    #[must_use]
+    pub fn from_signed_dms(sign: Sign, degrees: u32, minutes: u32, seconds: f64) -> Option<Self> {
+        let degrees = f64::from(degrees) + f64::from(minutes) / 60.0 + seconds / 3600.0;
+        let magnitude = Self::from_degrees(degrees)?;
+
+        Some(match sign {
+            Sign::Positive => magnitude,
+            Sign::Negative => -magnitude,
+        })
+    }
+
+    /// Create a new angle from a binary angle (BAM) with an arbitrary number
+    /// of bits, such that `2^bits` is a full turn. Any bits of `value` above
+    /// the low `bits` represent whole turns, and are discarded. Returns `None`
+    /// if `bits` is greater than 64.
+    #[inline]
+    #[must_use]
+    pub const fn from_bam_scaled(value: u64, bits: u32) -> Option<Self> {
+        match bits {
+            0 => Some(Self::from_repr(0)),
+            1..=64 => Some(Self::from_repr(value << (64 - bits))),
+            _ => None,
+        }
+    }
+
+    /// Create a new angle from the direction of the vector `(x, y)`, as with
+    /// the usual `atan2` function.
+    #[inline]
+    #[must_use]
+    pub fn atan2(y: f64, x: f64) -> Option<Self> {
+        Self::from_radians(libm::atan2(y, x))
+    }
+
+    /// Create a new angle from its sine, as with the usual `asin` function.
+    /// The result is in the range `[-90°, 90°]`, with negative angles in the
+    /// fourth quadrant. Returns `None` if `sin` is NaN or outside of
+    /// `[-1, 1]`.
+    #[inline]
+    #[must_use]
+    pub fn asin(sin: f64) -> Option<Self> {
+        Self::from_radians(libm::asin(sin))
+    }
+
+    /// Create a new angle from the length of a chord of a circle with the
+    /// given radius: the central angle subtended by the chord, which is
+    /// `2 * asin(chord / (2 * radius))`. This is the inverse of
+    /// [`chord_length`][Angle::chord_length], and the result is in the range
+    /// `[0, 180°]`. Returns `None` if the chord is negative or longer than
+    /// the diameter, or if the radius isn't positive.
+    #[must_use]
+    pub fn central_angle_from_chord(chord: f64, radius: f64) -> Option<Self> {
+        let valid = chord >= 0.0 && radius > 0.0;
+        if !valid {
+            return None;
+        }
+
+        Self::asin(chord / (2.0 * radius)).map(|half| half * 2)
+    }
+
+    /// Recover the angle of a 2x2 rotation matrix, in the row-major layout
+    /// produced by [`to_rotation_matrix`][Angle::to_rotation_matrix]. Only
+    /// the direction of the first column is considered, so any scaling or
+    /// shear in the matrix is ignored.
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_matrix(matrix: [[f64; 2]; 2]) -> Option<Self> {
+        Self::atan2(matrix[1][0], matrix[0][0])
+    }
+
+    /// Recover an angle from its sine and cosine. This uses both components,
+    /// so (unlike `asin` alone) it is stable across all 4 quadrants. This is
+    /// the inverse of [`sin_cos`][Angle::sin_cos].
+    #[inline]
+    #[must_use]
+    pub fn from_sin_cos(sin: f64, cos: f64) -> Option<Self> {
+        Self::atan2(sin, cos)
+    }
 
+    /// Get a lossless representation of this angle as an unsigned integer.
     #[inline]
     #[must_use]
-    pub fn repr(self) -> u64 {
+    pub const fn repr(self) -> u64 {
         self.0 .0
     }
 
+    /// Get the next representable angle below this one: the angle with a repr
+    /// one unit smaller, wrapping around at zero. Together with
+    /// [`next`][Angle::next], this exposes the quantization grid, which is
+    /// useful for interval-style reasoning about precision.
+    #[inline]
+    #[must_use]
+    pub const fn prev(self) -> Self {
+        Self::from_repr(self.0 .0.wrapping_sub(1))
+    }
+
+    /// Get the next representable angle above this one: the angle with a repr
+    /// one unit larger, wrapping around at a full turn.
+    #[inline]
+    #[must_use]
+    pub const fn next(self) -> Self {
+        Self::from_repr(self.0 .0.wrapping_add(1))
+    }
+
+    /// If this angle is exactly a whole number of degrees, as constructed by
+    /// [`from_degrees_exact_int`][Angle::from_degrees_exact_int], return that
+    /// number (in the range `0..360`). Angles that are merely very close to a
+    /// whole number of degrees, such as those produced by float conversions,
+    /// return `None`.
+    #[must_use]
+    pub const fn try_as_degrees_exact(self) -> Option<i32> {
+        // from_ratio truncates, so the only whole number of degrees that can
+        // produce this repr is the one found by rounding up.
+        let repr = self.0 .0 as u128;
+        let degrees = ((repr * 360) + (u64::MAX as u128)) >> 64;
+
+        if degrees >= 360 {
+            return None;
+        }
+
+        match Self::from_degrees_exact_int(degrees as i64).0 .0 == self.0 .0 {
+            true => Some(degrees as i32),
+            false => None,
+        }
+    }
+
+    /// Find the simplest fraction of a turn, `numerator / denominator`, that
+    /// is within `tolerance` of this angle, such as recognizing that an angle
+    /// is essentially 1/7 of a turn. "Simplest" means having the smallest
+    /// denominator, so the fraction is always in lowest terms, and zero is
+    /// `(0, 1)`. Only denominators up to 1024 are searched; returns `None` if
+    /// none of them are close enough.
+    #[must_use]
+    pub fn approx_rational_turn(self, tolerance: Angle) -> Option<(u64, u64)> {
+        const MAX_DENOMINATOR: u64 = 1024;
+
+        (1..=MAX_DENOMINATOR).find_map(|denominator| {
+            let scaled = self.0 .0 as u128 * denominator as u128;
+            let numerator = ((scaled + (1 << 63)) >> 64) as u64 % denominator;
+
+            let candidate = Self::from_ratio(numerator, denominator)?;
+
+            match candidate.abs_diff(self).0 .0 <= tolerance.0 .0 {
+                true => Some((numerator, denominator)),
+                false => None,
+            }
+        })
+    }
+
+    /// Check if this angle is an integer multiple of `divisor`, such as to
+    /// detect symmetry. Angles like 30 degrees can't be represented exactly,
+    /// so their reprs are truncated by up to one unit, and that error grows
+    /// by one unit with each multiple; this allows for it, so (for instance)
+    /// 90 degrees is a multiple of 30 degrees, even though its repr isn't an
+    /// exact multiple of the repr of 30 degrees. That is, this checks that
+    /// `self` is within `k` units of repr of `k * divisor`, for the nearest
+    /// whole number `k`. Only zero is a multiple of zero.
+    #[must_use]
+    pub const fn is_multiple_of(self, divisor: Angle) -> bool {
+        let value = self.0 .0 as u128;
+        let divisor = divisor.0 .0 as u128;
+
+        if divisor == 0 {
+            return value == 0;
+        }
+
+        let k = (value + divisor / 2) / divisor;
+        (k * divisor).abs_diff(value) <= k
+    }
+
+    /// Snap this angle to the nearest of `levels` equally spaced positions
+    /// around the circle, starting at zero. This is computed exactly on the
+    /// repr; ties round counterclockwise. Returns `None` if `levels` is zero.
+    #[must_use]
+    pub const fn quantize_uniform(self, levels: u32) -> Option<Self> {
+        if levels == 0 {
+            return None;
+        }
+
+        let scaled = self.0 .0 as u128 * levels as u128;
+        let index = ((scaled + (1 << 63)) >> 64) % levels as u128;
+
+        Self::from_ratio(index as u64, levels as u64)
+    }
+
+    /// Get this angle as a binary angle (BAM) with an arbitrary number of
+    /// bits, such that `2^bits` is a full turn. This is the top `bits` of the
+    /// repr; the rest are truncated. Returns `None` if `bits` is greater than
+    /// 64.
+    #[inline]
+    #[must_use]
+    pub const fn as_bam_scaled(self, bits: u32) -> Option<u64> {
+        match bits {
+            0 => Some(0),
+            1..=64 => Some(self.0 .0 >> (64 - bits)),
+            _ => None,
+        }
+    }
+
+    /// Get this angle as an exact fraction of a full turn, in the form
+    /// `(numerator, denominator)`. The denominator is always `2^64`.
+    #[inline]
+    #[must_use]
+    pub const fn as_exact_ratio(self) -> (u64, u128) {
+        (self.0 .0, 1 << 64)
+    }
+
+    /// Split this angle into whole units and sub-units, where a full turn is
+    /// `whole` units and each unit is `sub` sub-units; for instance,
+    /// `as_scaled_parts(360, 60)` gives degrees and arcminutes, and
+    /// `as_scaled_parts(24, 60)` gives hours and minutes. This is computed
+    /// exactly on the repr with integer arithmetic, rounding to the nearest
+    /// sub-unit, so the sub-units never read as `sub`; angles that round up
+    /// to a full turn wrap around to `(0, 0)`. If `sub` is zero, the whole
+    /// units are truncated instead.
+    #[must_use]
+    pub const fn as_scaled_parts(self, whole: u64, sub: u64) -> (u64, u64) {
+        let scaled = self.0 .0 as u128 * whole as u128;
+        let whole_part = (scaled >> 64) as u64;
+
+        let remainder = scaled as u64 as u128;
+        let sub_part = ((remainder * sub as u128 + (1 << 63)) >> 64) as u64;
+
+        match sub_part == sub && sub != 0 {
+            false => (whole_part, sub_part),
+            true if whole_part + 1 == whole => (0, 0),
+            true => (whole_part + 1, 0),
+        }
+    }
+
+    /// Get this angle as one of 256 evenly spaced facings, as commonly used
+    /// to store a heading in a single byte for sprites and other 2D game
+    /// objects. This is the top 8 bits of the repr (truncating the rest), so
+    /// 64 is a quarter turn. This is about the coarsest quantization that is
+    /// still useful for smooth rotation; each facing spans exactly four
+    /// entries of the internal sine table.
+    #[inline]
+    #[must_use]
+    pub const fn to_facing_u8(self) -> u8 {
+        (self.0 .0 >> 56) as u8
+    }
+
+    /// Create a new angle from one of 256 evenly spaced facings, as produced
+    /// by [`to_facing_u8`][Angle::to_facing_u8]. This is exact.
+    #[inline]
+    #[must_use]
+    pub const fn from_facing_u8(facing: u8) -> Self {
+        Self::from_repr((facing as u64) << 56)
+    }
+
+    /// Create a new angle from the big-endian bytes of its repr, as produced
+    /// by [`to_repr_bytes`][Angle::to_repr_bytes]. This is exact.
+    #[inline]
+    #[must_use]
+    pub const fn from_repr_bytes(bytes: &[u8; 8]) -> Self {
+        Self::from_repr(u64::from_be_bytes(*bytes))
+    }
+
+    /// Create a new angle from the first 8 bytes of `bytes`, interpreted as a
+    /// big-endian repr as with [`from_repr_bytes`][Angle::from_repr_bytes].
+    /// Any bytes after the first 8 are ignored, which is convenient when
+    /// decoding a stream. Returns `None` if there are fewer than 8 bytes.
+    #[inline]
+    #[must_use]
+    pub fn try_from_slice(bytes: &[u8]) -> Option<Self> {
+        bytes.first_chunk().map(Self::from_repr_bytes)
+    }
+
+    /// Get the big-endian bytes of the repr of this angle, suitable for
+    /// lossless transmission in binary protocols.
+    #[inline]
+    #[must_use]
+    pub const fn to_repr_bytes(self) -> [u8; 8] {
+        self.0 .0.to_be_bytes()
+    }
+
+    /// Get the repr of this angle reinterpreted as a signed integer, so that
+    /// angles in the second half turn read as negative. This is a fixed-point
+    /// fraction of a turn in the range [-0.5, 0.5).
+    #[inline]
+    #[must_use]
+    pub const fn as_signed_repr(self) -> i64 {
+        self.0 .0 as i64
+    }
+
+    /// Create a new angle from a signed fixed-point fraction of a turn, as
+    /// produced by [`as_signed_repr`][Angle::as_signed_repr]. Negative values
+    /// wrap around into the second half turn.
+    #[inline]
+    #[must_use]
+    pub const fn from_signed_repr(repr: i64) -> Self {
+        Self::from_repr(repr as u64)
+    }
+
+    /// Get this angle as a signed Q31 fraction of a turn, in the range
+    /// [-0.5, 0.5) turns, as used by DSP hardware. This is the top 32 bits of
+    /// `as_signed_repr`; the rest are truncated.
+    #[inline]
+    #[must_use]
+    pub const fn to_q31(self) -> i32 {
+        (self.as_signed_repr() >> 32) as i32
+    }
+
+    /// Create a new angle from a signed Q31 fraction of a turn, as produced
+    /// by [`to_q31`][Angle::to_q31]. This is exact.
+    #[inline]
+    #[must_use]
+    pub const fn from_q31(q31: i32) -> Self {
+        Self::from_signed_repr((q31 as i64) << 32)
+    }
+
     #[inline]
     #[must_use]
     pub fn as_rotations(self) -> f64 {
@@ -70,6 +638,78 @@ impl Angle {
         consts::DEGREES.mul(self.0).as_float()
     }
 
+    /// The size, in degrees, of the smallest representable step between two
+    /// angles: `360 / 2^64`, or about 1.95e-17. Every angle lies on this
+    /// fixed grid, so this bounds the quantization error of constructing an
+    /// angle by truncation; constructions that round to the nearest angle are
+    /// within half of it. There is currently only the one 64-bit angle type,
+    /// so this is a constant.
+    #[inline]
+    #[must_use]
+    pub const fn resolution_degrees() -> f64 {
+        360.0 / 18_446_744_073_709_551_616.0
+    }
+
+    /// Get this angle in an arbitrary unit, where `full` is the number of
+    /// units in a full turn; for instance, `as_scale(400.0)` gives gradians.
+    /// This is `as_rotations() * full`.
+    #[inline]
+    #[must_use]
+    pub fn as_scale(self, full: f64) -> f64 {
+        self.as_rotations() * full
+    }
+
+    /// Get this angle in degrees, in the window [lo, hi), which must be
+    /// exactly one turn wide; this generalizes the usual [0, 360) and
+    /// [-180, 180) ranges to any window, such as [-90, 270). Returns `None`
+    /// if `lo` isn't finite, or if `hi - lo` isn't (approximately) 360.
+    #[must_use]
+    pub fn wrap_into_signed_degrees_range(self, lo: f64, hi: f64) -> Option<f64> {
+        let valid = ((hi - lo) - 360.0).abs() < 1e-9;
+        if !valid {
+            return None;
+        }
+
+        let start = Self::from_degrees(lo)?;
+        Some(lo + (self - start).as_degrees())
+    }
+
+    /// Decompose this angle into whole degrees (in the range `0..360`) and
+    /// decimal minutes (in the range [0, 60)). The whole degrees are computed
+    /// exactly from the repr, so there's never a need to carry minutes that
+    /// round up to 60.
+    #[must_use]
+    pub fn to_degrees_minutes(self) -> (i32, f64) {
+        let scaled = (self.0 .0 as u128) * 360;
+
+        let degrees = (scaled >> 64) as i32;
+        let minutes = Repr::new(scaled as u64).as_float() * 60.0;
+
+        (degrees, minutes)
+    }
+
+    /// Decompose this angle, interpreted as a signed angle in the range
+    /// [-180, 180) degrees, into a sign and unsigned whole degrees, whole
+    /// minutes, and decimal seconds. This is the inverse of
+    /// [`from_signed_dms`][Angle::from_signed_dms]. As with
+    /// [`to_degrees_minutes`][Angle::to_degrees_minutes], the whole degrees
+    /// and minutes are computed exactly from the repr.
+    #[must_use]
+    pub fn to_signed_dms(self) -> (Sign, u32, u32, f64) {
+        let signed = self.as_signed_repr();
+        let scaled = (signed.unsigned_abs() as u128) * 360 * 60 * 60;
+
+        let seconds = (scaled >> 64) as u32;
+        let fraction = Repr::new(scaled as u64).as_float();
+
+        (
+            Sign::from_bit(signed < 0),
+            seconds / 3600,
+            (seconds / 60) % 60,
+            f64::from(seconds % 60) + fraction,
+        )
+    }
+
     #[inline]
     #[must_use]
     pub fn sin(self) -> f64 {
@@ -83,32 +723,2527 @@ impl Angle {
         trig::sin(self.repr().wrapping_add(quarter)).as_float()
     }
 
+    /// Compute the sine of this angle as a signed Q16.16 fixed point number,
+    /// such that 1.0 is 65536. This is computed directly from the fixed point
+    /// table, without passing through `f64`. The result saturates at the
+    /// poles, so it is always in the range `-65536..=65536`.
+    #[inline]
+    #[must_use]
+    pub fn sin_q16(self) -> i32 {
+        trig::sin(self.repr()).as_q16()
+    }
+
+    /// Get the length of the chord subtended by this angle on a circle with
+    /// the given radius: `2 * radius * sin(self / 2)`. The angle is measured
+    /// counterclockwise, so angles past a half turn give shorter chords, as
+    /// they approach a full turn.
+    #[inline]
+    #[must_use]
+    pub fn chord_length(self, radius: f64) -> f64 {
+        2.0 * radius * Self::from_repr(self.0 .0 >> 1).sin()
+    }
+
+    /// Compute the sine and cosine of this angle together, as signed Q31
+    /// fixed point numbers, such that `i32::MAX` is (just under) 1.0. These
+    /// are computed directly from the fixed point table, without passing
+    /// through `f64`, which makes them suitable for rotating Q31 coordinates
+    /// on integer-only hardware. The results saturate at the poles, so 1.0
+    /// is `i32::MAX` and -1.0 is `-i32::MAX`.
+    #[inline]
+    #[must_use]
+    pub fn sin_cos_q31(self) -> (i32, i32) {
+        (
+            trig::sin(self.repr()).as_q31(),
+            trig::sin(self.repr().wrapping_add(Self::QUARTER_TURN.0 .0)).as_q31(),
+        )
+    }
+
+    /// Compute the sine and cosine of this angle together.
+    #[inline]
+    #[must_use]
+    pub fn sin_cos(self) -> (f64, f64) {
+        (self.sin(), self.cos())
+    }
+
+    /// Get the unit vector `[cos, sin]` pointing in the direction of this
+    /// angle.
+    #[inline]
+    #[must_use]
+    pub fn to_unit_vector(self) -> [f64; 2] {
+        let (sin, cos) = self.sin_cos();
+        [cos, sin]
+    }
+
+    /// Rotate a 2D vector counterclockwise by this angle.
+    #[inline]
+    #[must_use]
+    pub fn rotate_vector(self, [x, y]: [f64; 2]) -> [f64; 2] {
+        let (sin, cos) = self.sin_cos();
+        [x * cos - y * sin, x * sin + y * cos]
+    }
+
+    /// Rotate a 2D point counterclockwise by this angle around a pivot at
+    /// `center`.
+    #[inline]
+    #[must_use]
+    pub fn rotate_point_around(self, point: [f64; 2], center: [f64; 2]) -> [f64; 2] {
+        let offset = [point[0] - center[0], point[1] - center[1]];
+        let [x, y] = self.rotate_vector(offset);
+
+        [x + center[0], y + center[1]]
+    }
+
+    /// Get the 2x2 matrix that rotates column vectors counterclockwise by
+    /// this angle, in row-major order: `[[cos, -sin], [sin, cos]]`.
+    #[inline]
+    #[must_use]
+    pub fn to_rotation_matrix(self) -> [[f64; 2]; 2] {
+        let (sin, cos) = self.sin_cos();
+        [[cos, -sin], [sin, cos]]
+    }
+
     #[inline]
     #[must_use]
     pub fn tan(self) -> f64 {
         self.sin() / self.cos()
     }
-}
 
-#[cfg(test)]
-mod conversion_tests {
-    use super::Angle;
+    /// Get the exsecant of this angle, `sec - 1`. This is infinite where the
+    /// secant is, at a quarter turn and three quarters of a turn.
+    #[inline]
+    #[must_use]
+    pub fn exsec(self) -> f64 {
+        1.0 / self.cos() - 1.0
+    }
 
-    #[test]
-    fn test_half_rotations() {
-        let angle = Angle::from_repr(0x80_00_00_00_00_00_00_00);
-        assert_eq!(angle.as_rotations(), 0.5)
+    /// Get the excosecant of this angle, `csc - 1`. This is infinite where
+    /// the cosecant is, at zero and a half turn.
+    #[inline]
+    #[must_use]
+    pub fn excsc(self) -> f64 {
+        1.0 / self.sin() - 1.0
     }
 
-    #[test]
-    fn test_half_degrees() {
-        let angle = Angle::from_repr(0x80_00_00_00_00_00_00_00);
-        assert_eq!(angle.as_degrees(), 180.0)
+    /// Create a new angle from a slope expressed as a percent grade (rise over
+    /// run, times 100), as used for roads and terrain. An infinite grade is a
+    /// vertical slope. Returns `None` if the grade is NaN.
+    #[inline]
+    #[must_use]
+    pub fn from_percent_grade(grade: f64) -> Option<Self> {
+        Self::atan2(grade, 100.0)
     }
 
-    #[test]
-    fn test_half_radians() {
-        let angle = Angle::from_repr(0x80_00_00_00_00_00_00_00);
-        assert_eq!(angle.as_radians(), core::f64::consts::PI)
+    /// Get the slope of this angle as a percent grade (rise over run, times
+    /// 100). A vertical slope (a quarter turn) has an infinite grade.
+    #[inline]
+    #[must_use]
+    pub fn as_percent_grade(self) -> f64 {
+        self.tan() * 100.0
+    }
+
+    /// Estimate the worst-case absolute error of [`sin`][Angle::sin] for this
+    /// specific angle. The error is smallest for angles that land exactly on
+    /// an entry of the internal lookup table, and grows towards the middle of
+    /// the gap between entries.
+    #[inline]
+    #[must_use]
+    pub fn sin_error_bound(self) -> f64 {
+        trig::sin_error_bound(self.repr())
+    }
+
+    /// Compute the sine of this angle with a more accurate (but slower)
+    /// interpolation of the internal lookup table. Where [`sin`][Angle::sin]
+    /// uses a first-order expansion around a single table entry, with an
+    /// error of up to about 2e-5, this fits a quadratic through three
+    /// adjacent entries, reducing the worst-case error to about 1.5e-8
+    /// without enlarging the table.
+    #[inline]
+    #[must_use]
+    pub fn sin_quadratic(self) -> f64 {
+        trig::sin_quadratic(self.repr())
+    }
+
+    /// Approximate `sin(self + epsilon)` for a small `epsilon`, using the
+    /// first order expansion `sin(self) + epsilon * cos(self)` (the same
+    /// identity the lookup table uses internally). `epsilon` is interpreted
+    /// as a signed angle, so small clockwise offsets work too. The error of
+    /// the expansion is at most `epsilon² / 2` (with `epsilon` in radians),
+    /// on top of the error of `sin` itself, so this is only suitable for
+    /// offsets much smaller than a radian.
+    #[inline]
+    #[must_use]
+    pub fn sin_plus_small(self, epsilon: Angle) -> f64 {
+        let (sin, cos) = self.sin_cos();
+        sin + SignedAngle::from_angle(epsilon).as_radians() * cos
+    }
+
+    /// Get the derivative of the sine at this angle with respect to turns:
+    /// `d(sin)/d(turns)`, which by the chain rule is `τ * cos(self)`. Divide
+    /// by `τ` (that is, just use [`cos`][Angle::cos]) for the rate per
+    /// radian.
+    #[inline]
+    #[must_use]
+    pub fn sin_rate_per_turn(self) -> f64 {
+        f64_consts::TAU * self.cos()
+    }
+
+    /// Get the sign of the sine of this angle, without computing it. This is
+    /// positive in the first half turn and negative in the second.
+    #[inline]
+    #[must_use]
+    pub const fn sin_sign(self) -> Sign {
+        Sign::from_bit(self.0 .0 >> 63 != 0)
+    }
+
+    /// Get the sign of the cosine of this angle, without computing it. This
+    /// is positive in the first and last quarter turns, and negative in the
+    /// middle two.
+    #[inline]
+    #[must_use]
+    pub const fn cos_sign(self) -> Sign {
+        let repr = self.0 .0;
+        Sign::from_bit((repr ^ (repr << 1)) >> 63 != 0)
+    }
+
+    /// Get the octant (eighth of a turn) containing this angle, in the range
+    /// 0..8, counting from 0.
+    #[inline]
+    #[must_use]
+    pub const fn octant(self) -> u8 {
+        (self.0 .0 >> 61) as u8
+    }
+
+    /// Split this angle into its quadrant (in the range 0..4) and the
+    /// remaining offset into that quadrant, in the range [0, 0.25) turns.
+    /// The offset is always measured counterclockwise from the start of the
+    /// quadrant (it's never reflected), so `self` is exactly
+    /// `quadrant * 90° + offset`.
+    #[inline]
+    #[must_use]
+    pub const fn reduce_to_quadrant(self) -> (u8, Self) {
+        let quadrant = (self.0 .0 >> 62) as u8;
+        let offset = self.0 .0 & (u64::MAX >> 2);
+
+        (quadrant, Self::from_repr(offset))
+    }
+
+    /// Reduce this angle into the range [0, 1/n) turns, for n-fold rotational
+    /// symmetry, so that every angle that differs by a multiple of `1/n`
+    /// turns folds to the same result. This is computed exactly, as the
+    /// fractional part of `self * n` scaled back down by `n` (with the final
+    /// division truncating). Returns `None` if `n` is zero.
+    #[inline]
+    #[must_use]
+    pub const fn fold_period(self, n: u32) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+
+        let scaled = self.0 .0.wrapping_mul(n as u64);
+        Some(Self::from_repr(scaled / n as u64))
+    }
+
+    /// Fold this angle into the first half turn, returning the sign of its
+    /// sine along with the folded angle, in the range [0, 0.5) turns. The
+    /// fold is a subtraction, not a reflection: angles in the second half
+    /// turn have a half turn subtracted, so 200° folds to 20° (not 160°).
+    /// Since `sin(x + 0.5 turns) = -sin(x)`, this is the split needed to
+    /// evaluate any odd half-periodic function on just the first half turn.
+    #[inline]
+    #[must_use]
+    pub const fn half_turn_fold(self) -> (Sign, Self) {
+        let offset = self.0 .0 & (u64::MAX >> 1);
+
+        (self.sin_sign(), Self::from_repr(offset))
+    }
+
+    /// Get the octant of this angle, along with the signs of its sine and
+    /// cosine, all from a single inspection of the top bits of the repr.
+    #[inline]
+    #[must_use]
+    pub const fn octant_signs(self) -> (u8, Sign, Sign) {
+        let octant = self.octant();
+        let sin = Sign::from_bit(octant & 0b100 != 0);
+        let cos = Sign::from_bit(((octant >> 2) ^ (octant >> 1)) & 1 != 0);
+
+        (octant, sin, cos)
+    }
+
+    /// Iterate over the angles `start + k * increment`, for `k = 0, 1, 2...`,
+    /// stopping before the total distance travelled from `start` reaches a
+    /// full turn. If `increment` evenly divides the turn, this yields exactly
+    /// one full cycle, ending just before `start` would repeat; otherwise the
+    /// last angle is the final step that falls short of a full turn. A zero
+    /// `increment` yields only `start`.
+    pub fn step_iter(start: Angle, increment: Angle) -> impl Iterator<Item = Angle> {
+        const FULL_TURN: u128 = 1 << 64;
+
+        let step = increment.repr() as u128;
+        let mut offset: u128 = 0;
+
+        core::iter::from_fn(move || {
+            if offset >= FULL_TURN {
+                return None;
+            }
+
+            let angle = Self::from_repr(start.repr().wrapping_add(offset as u64));
+            offset = if step == 0 { FULL_TURN } else { offset + step };
+            Some(angle)
+        })
+    }
+
+    /// Get the distance between this angle and `other` along the shorter arc
+    /// between them. This is always in the range [0, 0.5] turns.
+    #[inline]
+    #[must_use]
+    pub const fn abs_diff(self, other: Angle) -> Self {
+        let diff = other.0 .0.wrapping_sub(self.0 .0);
+        let reverse = diff.wrapping_neg();
+
+        Self::from_repr(if diff < reverse { diff } else { reverse })
+    }
+
+    /// Find the candidate closest to this angle, measured along the shorter
+    /// arc (as with `abs_diff`), and return it along with its index. If
+    /// several candidates are equally close, the one with the lowest index is
+    /// returned. Returns `None` if there are no candidates.
+    #[must_use]
+    pub fn closest_of(self, candidates: &[Angle]) -> Option<(usize, Angle)> {
+        candidates
+            .iter()
+            .copied()
+            .enumerate()
+            .min_by_key(|&(_, candidate)| self.abs_diff(candidate).repr())
+    }
+
+    /// Compare this angle and `other` by their counterclockwise distance from
+    /// `reference`, so that `reference` itself sorts first and the angle just
+    /// clockwise of it sorts last. This makes it easy to sort a collection of
+    /// angles around an arbitrary starting point, with `sort_by`.
+    #[inline]
+    #[must_use]
+    pub fn cmp_ccw_from(self, other: Angle, reference: Angle) -> Ordering {
+        (self - reference).repr().cmp(&(other - reference).repr())
+    }
+
+    /// Measure the arc from this angle to `other`, travelling in the given
+    /// direction, as a number of turns in the range [0, 1).
+    #[inline]
+    #[must_use]
+    pub fn turns_between(self, other: Angle, direction: Direction) -> f64 {
+        let arc = match direction {
+            Direction::Ccw => other.0 .0.wrapping_sub(self.0 .0),
+            Direction::Cw => self.0 .0.wrapping_sub(other.0 .0),
+        };
+
+        Repr::new(arc).as_float()
+    }
+
+    /// Count how many times a rotation from `prev` to `current`, travelling
+    /// in the given direction, crosses zero. This is for dead reckoning with
+    /// a wrapping sensor, such as an absolute encoder on a shaft that turns
+    /// in a known direction: the total rotation is the sum of the wrap counts
+    /// plus the final reading. It assumes that the shaft turns by less than a
+    /// full turn between readings, so the result is `1` for a
+    /// counterclockwise wrap, `-1` for a clockwise one, or `0`.
+    #[inline]
+    #[must_use]
+    pub const fn wraps_between(prev: Angle, current: Angle, direction: Direction) -> i64 {
+        match direction {
+            Direction::Ccw if current.0 .0 < prev.0 .0 => 1,
+            Direction::Cw if current.0 .0 > prev.0 .0 => -1,
+            _ => 0,
+        }
+    }
+
+    /// Express this angle relative to `center`, as the shortest signed
+    /// rotation from `center` to `self`. This is in the range [-0.5, 0.5)
+    /// turns, so it's suitable for computing the error from a setpoint.
+    #[inline]
+    #[must_use]
+    pub const fn normalize_to(self, center: Angle) -> SignedAngle {
+        SignedAngle::from_repr(self.0 .0.wrapping_sub(center.0 .0) as i64)
+    }
+
+    /// Get the shortest signed rotation from this angle to `other`, in the
+    /// range [-0.5, 0.5) turns. Positive distances are counterclockwise.
+    #[inline]
+    #[must_use]
+    pub const fn signed_distance(self, other: Angle) -> SignedAngle {
+        other.normalize_to(self)
+    }
+
+    /// Get the direction of the shortest turn from `from` to this angle:
+    /// `Positive` for counterclockwise, or `Negative` for clockwise. Returns
+    /// `None` if the angles are exactly a half turn apart, so that both
+    /// directions are equally short. Equal angles are reported as `Positive`.
+    #[inline]
+    #[must_use]
+    pub const fn sign_of_turn(self, from: Angle) -> Option<Sign> {
+        match from.signed_distance(self).repr() {
+            i64::MIN => None,
+            distance => Some(Sign::from_bit(distance < 0)),
+        }
+    }
+
+    /// Rotate this angle toward `target` along the shorter arc, by at most
+    /// `max_step`. If the target is within `max_step`, it's returned exactly.
+    /// This is the usual building block for turret or camera tracking with a
+    /// limited turn rate.
+    #[inline]
+    #[must_use]
+    pub const fn step_toward(self, target: Angle, max_step: Angle) -> Self {
+        let distance = self.signed_distance(target).repr();
+
+        if distance.unsigned_abs() <= max_step.0 .0 {
+            target
+        } else if distance < 0 {
+            Self::from_repr(self.0 .0.wrapping_sub(max_step.0 .0))
+        } else {
+            Self::from_repr(self.0 .0.wrapping_add(max_step.0 .0))
+        }
+    }
+
+    /// Limit the rate of change of a commanded angle: treating `self` as the
+    /// desired value and `previous` as the value from the previous step, get
+    /// the angle closest to `self` that is at most `max_rate` away from
+    /// `previous` along the shorter arc. This is the same operation as
+    /// [`step_toward`][Angle::step_toward], framed as a slew rate limiter on
+    /// the command rather than as a motion of `previous`.
+    #[inline]
+    #[must_use]
+    pub const fn clamp_rate(self, previous: Angle, max_rate: Angle) -> Self {
+        previous.step_toward(self, max_rate)
+    }
+
+    /// Rotate this angle toward the direction of the vector `target`, by at
+    /// most `max_step`, as with [`step_toward`][Angle::step_toward]. If the
+    /// target vector is zero or not finite, it has no direction, so the angle
+    /// is returned unchanged.
+    #[must_use]
+    pub fn rotate_toward_vector(self, [x, y]: [f64; 2], max_step: Angle) -> Self {
+        if x == 0.0 && y == 0.0 {
+            return self;
+        }
+
+        match Self::atan2(y, x) {
+            Some(target) => self.step_toward(target, max_step),
+            None => self,
+        }
+    }
+
+    /// Get the error from this angle to a `setpoint`, in degrees, wrapped
+    /// into the range [-180, 180). This is the shortest signed rotation that
+    /// would bring `self` to the setpoint, so it's immune to the usual
+    /// wraparound bug in heading controllers.
+    #[inline]
+    #[must_use]
+    pub fn error_degrees(self, setpoint: Angle) -> f64 {
+        self.signed_distance(setpoint).as_degrees()
+    }
+
+    /// Check if this angle lies within a cone of vision (field of view)
+    /// centered on `center`, which extends `half_width` to either side. The
+    /// edges of the cone are inclusive, and the cone may freely wrap around
+    /// zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_within_fov(self, center: Angle, half_width: Angle) -> bool {
+        self.abs_diff(center).0 .0 <= half_width.0 .0
+    }
+
+    /// Add two angles, returning `None` if the sum passes through zero (that
+    /// is, if it completes a full turn).
+    #[inline]
+    #[must_use]
+    pub const fn checked_add(self, rhs: Angle) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(sum) => Some(Self(sum)),
+            None => None,
+        }
+    }
+
+    /// Add two angles, wrapping around a full turn. Also returns whether the
+    /// sum passed through zero.
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_add(self, rhs: Angle) -> (Self, bool) {
+        let (sum, wrapped) = self.0.overflowing_add(rhs.0);
+        (Self(sum), wrapped)
+    }
+
+    /// Advance this angle by an angular velocity `omega` (in turns per
+    /// second) over a time step `dt` (in seconds). The step is reduced into a
+    /// single turn before it's added, so repeatedly stepping an angle never
+    /// drifts out of range. Returns `None` if `omega * dt` isn't finite.
+    #[inline]
+    #[must_use]
+    pub fn angular_velocity_step(self, omega: f64, dt: f64) -> Option<Self> {
+        Self::from_rotations(omega * dt).map(|step| self + step)
+    }
+
+    /// Scale this angle by a real factor, such as to rotate one and a half
+    /// times as far. This scales the shortest signed representation of the
+    /// angle, in the range [-0.5, 0.5) turns, rather than the unsigned one,
+    /// so that small clockwise rotations stay small and clockwise; the result
+    /// wraps around full turns. If the product isn't finite, the result is
+    /// zero.
+    #[inline]
+    #[must_use]
+    pub fn signed_mul_f64(self, scalar: f64) -> Self {
+        let rotations = SignedAngle::from_angle(self).as_rotations() * scalar;
+        Self::from_rotations(rotations).unwrap_or_default()
+    }
+
+    /// Compute how long it will take for this angle, rotating at `rate` (in
+    /// turns per second), to next line up with the stationary angle `other`.
+    /// Positive rates rotate counterclockwise and negative rates clockwise;
+    /// either way the result is the next alignment in the future, so it's
+    /// never negative. Returns `Some(0.0)` if the angles are already aligned,
+    /// and `None` if they differ and `rate` is zero or NaN.
+    #[must_use]
+    pub fn time_to_align(self, other: Angle, rate: f64) -> Option<f64> {
+        if self == other {
+            return Some(0.0);
+        }
+
+        let distance = match rate {
+            rate if rate > 0.0 => other - self,
+            rate if rate < 0.0 => self - other,
+            _ => return None,
+        };
+
+        Some(distance.as_rotations() / rate.abs())
+    }
+
+    /// Linearly interpolate from this angle to `other` along the shorter arc
+    /// between them, where `t = 0` is `self` and `t = 1` is `other`. Both
+    /// endpoints are reproduced exactly, and values of `t` in between are
+    /// interpolated in exact fixed point. Values of `t` outside of [0, 1]
+    /// extrapolate along the same arc.
+    #[must_use]
+    pub fn lerp(self, other: Angle, t: f64) -> Self {
+        let delta = self.signed_distance(other).repr();
+
+        // The fixed point interpolation always runs counterclockwise, so if
+        // the shorter arc is clockwise, interpolate between the negated
+        // angles instead.
+        if (0.0..1.0).contains(&t) {
+            if let Some(t) = Repr::from_float(t) {
+                return match delta >= 0 {
+                    true => Self(self.0.lerp(other.0, t)),
+                    false => -Self((-self).0.lerp((-other).0, t)),
+                };
+            }
+        }
+
+        // Extrapolate from whichever endpoint is closer, so that t = 1 is
+        // exact. The offset can be more than a turn, so it's wrapped (via
+        // i128) rather than saturated.
+        let delta = delta as f64;
+
+        if t <= 0.5 {
+            self + Self::from_repr((delta * t) as i128 as u64)
+        } else {
+            other - Self::from_repr((delta * (1.0 - t)) as i128 as u64)
+        }
+    }
+
+    /// Interpolate between `p1` and `p2` with a (uniform) Catmull-Rom spline,
+    /// using `p0` and `p3` as the neighboring control points, where `t = 0` is
+    /// `p1` and `t = 1` is `p2`. Every step between adjacent control points
+    /// is taken along the shorter arc, so a sequence of angles that crosses
+    /// zero is interpolated smoothly. Both endpoints are reproduced exactly.
+    #[must_use]
+    pub fn catmull_rom(p0: Angle, p1: Angle, p2: Angle, p3: Angle, t: f64) -> Self {
+        let d01 = p0.signed_distance(p1).repr() as f64;
+        let d12 = p1.signed_distance(p2).repr() as f64;
+        let d23 = p2.signed_distance(p3).repr() as f64;
+
+        // The tangents at p1 and p2
+        let m1 = (d01 + d12) / 2.0;
+        let m2 = (d12 + d23) / 2.0;
+
+        // The cubic Hermite basis functions, which are exactly 0 or 1 at the
+        // endpoints
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = 3.0 * t2 - 2.0 * t3;
+        let h11 = t3 - t2;
+
+        // Measure the offset from whichever endpoint is closer, so that both
+        // endpoints are exact. The offset may be more than a half turn, so
+        // it's wrapped through an i128 rather than saturated.
+        let (origin, offset) = if t <= 0.5 {
+            (p1, h10 * m1 + h11 * m2 + h01 * d12)
+        } else {
+            (p2, h10 * m1 + h11 * m2 - h00 * d12)
+        };
+
+        origin + Self::from_repr(offset as i128 as u64)
+    }
+
+    /// Sample a keyframed animation of an angle at time `t`. `keys` is a list
+    /// of `(time, angle)` keyframes, which must be sorted by time; the two
+    /// keyframes surrounding `t` are interpolated along the shorter arc
+    /// between them, with [`lerp`][Angle::lerp]. Times before the first
+    /// keyframe or after the last one are clamped to that keyframe. Returns
+    /// `None` if there are no keyframes.
+    #[must_use]
+    pub fn sample_keyframes(keys: &[(f64, Angle)], t: f64) -> Option<Self> {
+        let &(first_time, first) = keys.first()?;
+
+        if t <= first_time {
+            return Some(first);
+        }
+
+        for window in keys.windows(2) {
+            let [(start_time, start), (end_time, end)] = [window[0], window[1]];
+
+            if t < end_time {
+                let fraction = (t - start_time) / (end_time - start_time);
+                return Some(start.lerp(end, fraction));
+            }
+        }
+
+        keys.last().map(|&(_, last)| last)
+    }
+
+    /// Linearly map this angle from the input arc to the output arc, such as
+    /// to map the physical travel of a gauge needle onto its dial. Both arcs
+    /// are measured counterclockwise from their `lo` end to their `hi` end,
+    /// so (for instance) an input arc from 300° to 60° is the 120° arc that
+    /// passes through zero, not the 240° arc that doesn't; an output arc can
+    /// be traversed clockwise by swapping its ends and mapping onto the
+    /// complementary arc instead. Angles outside of the input arc are clamped
+    /// to whichever end of it is closer. The mapping is computed exactly in
+    /// fixed point. If the input arc is empty, the result is `out_lo`.
+    #[must_use]
+    pub const fn remap_range(
+        self,
+        in_lo: Angle,
+        in_hi: Angle,
+        out_lo: Angle,
+        out_hi: Angle,
+    ) -> Self {
+        let in_span = in_hi.0 .0.wrapping_sub(in_lo.0 .0);
+        let out_span = out_hi.0 .0.wrapping_sub(out_lo.0 .0);
+
+        if in_span == 0 {
+            return out_lo;
+        }
+
+        let offset = self.0 .0.wrapping_sub(in_lo.0 .0);
+
+        // Outside the arc, compare the distance past the hi end to the
+        // distance before the lo end.
+        let offset = if offset <= in_span {
+            offset
+        } else if offset - in_span < offset.wrapping_neg() {
+            in_span
+        } else {
+            0
+        };
+
+        let mapped = (offset as u128 * out_span as u128) / in_span as u128;
+        Self::from_repr(out_lo.0 .0.wrapping_add(mapped as u64))
+    }
+
+    /// Interpolate from `a` to `b`, choosing between the shorter and longer
+    /// arcs to stay continuous with `prev`, the result of the previous frame.
+    /// Always taking the shorter arc causes a pop when `b - a` crosses a half
+    /// turn, because the shorter arc suddenly switches sides; instead, this
+    /// interpolates along both arcs and returns whichever result is closer to
+    /// `prev`. Without a `prev` (on the first frame), or in the case of a
+    /// tie, the shorter arc is used, exactly as with [`lerp`][Angle::lerp].
+    #[must_use]
+    pub fn blend_shortest_pair(a: Angle, b: Angle, t: f64, prev: Option<Angle>) -> Self {
+        let short = a.lerp(b, t);
+
+        let Some(prev) = prev else { return short };
+
+        // The longer arc goes the other way around, so it's a full turn
+        // different from the shorter one
+        let delta = a.signed_distance(b).repr() as i128;
+        let delta = match delta < 0 {
+            true => delta + (1 << 64),
+            false => delta - (1 << 64),
+        };
+        let long = a + Self::from_repr((delta as f64 * t) as i128 as u64);
+
+        match prev.abs_diff(long).repr() < prev.abs_diff(short).repr() {
+            true => long,
+            false => short,
+        }
+    }
+
+    /// Interpolate from this angle to `other` along the shorter arc between
+    /// them, as with [`lerp`][Angle::lerp], and return the unit vector in the
+    /// interpolated direction.
+    #[inline]
+    #[must_use]
+    pub fn slerp_vector(self, other: Angle, t: f64) -> [f64; 2] {
+        self.lerp(other, t).to_unit_vector()
+    }
+
+    /// Bilinearly interpolate a grid cell of angles, such as a cell of a flow
+    /// field or wind map. The corners are at the `(u, v)` coordinates
+    /// `[(0, 0), (1, 0), (0, 1), (1, 1)]`, in that order. This interpolates
+    /// along `u` on each edge of the cell, then along `v` between the
+    /// results, with each interpolation done along the shorter arc as with
+    /// [`lerp`][Angle::lerp].
+    #[must_use]
+    pub fn bilinear_angle(corners: [Angle; 4], u: f64, v: f64) -> Self {
+        let [a, b, c, d] = corners;
+
+        a.lerp(b, u).lerp(c.lerp(d, u), v)
+    }
+
+    /// Get the sinusoidal ease-in-out curve at this angle, `(1 - cos) / 2`.
+    /// This rises smoothly from 0 at zero to 1 at a half turn (and falls back
+    /// over the second half), so a half turn of progress maps to a full
+    /// animation. Since `cos` is exact at the cardinal angles, the endpoints
+    /// are exactly 0 and 1.
+    #[inline]
+    #[must_use]
+    pub fn ease_in_out_sine(self) -> f64 {
+        (1.0 - self.cos()) / 2.0
+    }
+
+    /// Convert a zenith distance (the angle down from straight overhead) to
+    /// an altitude (the angle up from the horizon). These are complements,
+    /// so this is exactly `QUARTER_TURN - self`. Zenith distances beyond a
+    /// quarter turn give negative altitudes (below the horizon), which wrap
+    /// around into the fourth quadrant.
+    #[inline]
+    #[must_use]
+    pub const fn altitude_from_zenith(self) -> Self {
+        Self::from_repr(Self::QUARTER_TURN.0 .0.wrapping_sub(self.0 .0))
+    }
+
+    /// Convert an altitude (the angle up from the horizon) to a zenith
+    /// distance (the angle down from straight overhead). This is the inverse
+    /// of [`altitude_from_zenith`][Angle::altitude_from_zenith], and is also
+    /// exactly `QUARTER_TURN - self`; negative altitudes should be given as
+    /// angles in the fourth quadrant.
+    #[inline]
+    #[must_use]
+    pub const fn zenith_from_altitude(self) -> Self {
+        Self::from_repr(Self::QUARTER_TURN.0 .0.wrapping_sub(self.0 .0))
+    }
+
+    /// Get the complex conjugate of this angle, treated as the phase of a
+    /// phasor. Conjugating a phasor negates its phase, so this is the same
+    /// as negation; equivalently, it reverses the direction of time for a
+    /// rotating signal.
+    #[inline]
+    #[must_use]
+    pub const fn conjugate(self) -> Self {
+        Self::from_repr(self.0 .0.wrapping_neg())
+    }
+
+    /// Mirror this angle across the horizontal (x) axis, mapping `[x, y]`
+    /// to `[x, -y]`. This is exactly negation.
+    #[inline]
+    #[must_use]
+    pub const fn reflect_x(self) -> Self {
+        self.conjugate()
+    }
+
+    /// Mirror this angle across the vertical (y) axis, mapping `[x, y]` to
+    /// `[-x, y]`. This is exactly `HALF_TURN - self`.
+    #[inline]
+    #[must_use]
+    pub const fn reflect_y(self) -> Self {
+        Self::from_repr(Self::HALF_TURN.0 .0.wrapping_sub(self.0 .0))
+    }
+
+    /// Reflect this angle through the origin, mapping `[x, y]` to `[-x, -y]`.
+    /// This is exactly `self + HALF_TURN`: the opposite direction.
+    #[inline]
+    #[must_use]
+    pub const fn point_reflect(self) -> Self {
+        Self::from_repr(self.0 .0.wrapping_add(Self::HALF_TURN.0 .0))
+    }
+
+    /// Bisect this angle, interpreted as the region swept counterclockwise
+    /// from zero to `self`. The result is exactly half of `self`. See also
+    /// `bisect_reflex`, which bisects the complementary region instead.
+    #[inline]
+    #[must_use]
+    pub const fn bisect(self) -> Self {
+        Self::from_repr(self.0 .0 >> 1)
+    }
+
+    /// Bisect the reflex complement of this angle: the region swept
+    /// clockwise from zero to `self`, rather than counterclockwise. The
+    /// result is always a half turn away from `bisect`.
+    #[inline]
+    #[must_use]
+    pub const fn bisect_reflex(self) -> Self {
+        Self::from_repr((self.0 .0 >> 1).wrapping_add(1 << 63))
+    }
+
+    /// Assert that this angle is within `tolerance` of `expected`, measured
+    /// along the shorter arc between them. On failure, this panics with a
+    /// message showing both angles and their difference in degrees. This
+    /// check is always performed, even without `debug_assertions`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the angles are further apart than `tolerance`.
+    #[track_caller]
+    pub fn assert_near(self, expected: Angle, tolerance: Angle) {
+        let difference = self.abs_diff(expected);
+
+        assert!(
+            difference.repr() <= tolerance.repr(),
+            "angle {}° is not within {}° of {}° (difference: {}°)",
+            self.as_degrees(),
+            tolerance.as_degrees(),
+            expected.as_degrees(),
+            difference.as_degrees(),
+        );
+    }
+
+    /// Find a bisector of this angle and `other`. There are always two
+    /// bisectors, a half turn apart; `prefer` selects between the bisector of
+    /// the shorter arc and that of the longer arc. If the angles are exactly
+    /// a half turn apart, the internal bisector is the one counterclockwise
+    /// from `self`.
+    #[must_use]
+    pub const fn bisector(self, other: Angle, prefer: BisectorSide) -> Self {
+        let half: u64 = 1 << 63;
+
+        let diff = other.0 .0.wrapping_sub(self.0 .0);
+        let midpoint = self.0 .0.wrapping_add(diff / 2);
+
+        let internal = if diff <= half {
+            midpoint
+        } else {
+            midpoint.wrapping_add(half)
+        };
+
+        match prefer {
+            BisectorSide::Internal => Self::from_repr(internal),
+            BisectorSide::External => Self::from_repr(internal.wrapping_add(half)),
+        }
+    }
+}
+
+impl Add for Angle {
+    type Output = Angle;
+
+    #[inline]
+    fn add(self, rhs: Angle) -> Angle {
+        Self::from_repr(self.0 .0.wrapping_add(rhs.0 .0))
+    }
+}
+
+impl Sub for Angle {
+    type Output = Angle;
+
+    #[inline]
+    fn sub(self, rhs: Angle) -> Angle {
+        Self::from_repr(self.0 .0.wrapping_sub(rhs.0 .0))
+    }
+}
+
+/// Multiply an angle by a whole number, wrapping around full turns. This is
+/// exact, so it's suitable for computing harmonics of a phase.
+impl Mul<u64> for Angle {
+    type Output = Angle;
+
+    #[inline]
+    fn mul(self, rhs: u64) -> Angle {
+        Self::from_repr(self.0 .0.wrapping_mul(rhs))
+    }
+}
+
+impl Neg for Angle {
+    type Output = Angle;
+
+    #[inline]
+    fn neg(self) -> Angle {
+        self.conjugate()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Angle {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u64::arbitrary(u).map(Self::from_repr)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod conversion_tests {
+    use super::Angle;
+    use crate::sign::Sign;
+
+    #[test]
+    fn test_half_rotations() {
+        let angle = Angle::from_repr(0x80_00_00_00_00_00_00_00);
+        assert_eq!(angle.as_rotations(), 0.5)
+    }
+
+    #[test]
+    fn test_half_degrees() {
+        let angle = Angle::from_repr(0x80_00_00_00_00_00_00_00);
+        assert_eq!(angle.as_degrees(), 180.0)
+    }
+
+    #[test]
+    fn test_half_radians() {
+        let angle = Angle::from_repr(0x80_00_00_00_00_00_00_00);
+        assert_eq!(angle.as_radians(), core::f64::consts::PI)
+    }
+
+    #[test]
+    fn test_resolution_degrees() {
+        let resolution = Angle::resolution_degrees();
+
+        assert!((resolution - 1.95e-17).abs() < 0.01e-17);
+        let step = Angle::from_repr(1 << 40).as_degrees() / (1u64 << 40) as f64;
+        assert!((step - resolution).abs() < 1e-9 * resolution);
+    }
+
+    #[test]
+    fn test_scale() {
+        let angle = Angle::from_degrees_exact_int(90);
+
+        assert_eq!(angle.as_scale(400.0), 100.0);
+        assert_eq!(Angle::from_scale(100.0, 400.0), Some(angle));
+
+        let angle = Angle::from_scale(60.0, 50.0).unwrap();
+        assert!((angle.as_degrees() - 72.0).abs() < 1e-9);
+        assert_eq!(Angle::from_scale(1.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_exsec() {
+        assert_eq!(Angle::from_repr(0).exsec(), 0.0);
+        assert_eq!(Angle::from_degrees_exact_int(90).exsec(), f64::INFINITY);
+        assert_eq!(Angle::from_degrees_exact_int(180).exsec(), -2.0);
+
+        let exsec = Angle::from_degrees_exact_int(60).exsec();
+        assert!((exsec - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_asin() {
+        assert!(
+            Angle::asin(0.5)
+                .unwrap()
+                .abs_diff(Angle::from_degrees_exact_int(30))
+                .repr()
+                <= 1 << 12
+        );
+        assert!(
+            Angle::asin(-1.0)
+                .unwrap()
+                .abs_diff(Angle::from_degrees_exact_int(270))
+                .repr()
+                <= 1 << 12
+        );
+        assert_eq!(Angle::asin(0.0), Some(Angle::ZERO));
+        assert_eq!(Angle::asin(1.5), None);
+        assert_eq!(Angle::asin(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_chord_length() {
+        assert!((Angle::HALF_TURN.chord_length(3.0) - 6.0).abs() < 1e-9);
+        assert!((Angle::from_degrees_exact_int(60).chord_length(2.0) - 2.0).abs() < 1e-4);
+        assert!((Angle::from_degrees_exact_int(300).chord_length(2.0) - 2.0).abs() < 1e-4);
+        assert_eq!(Angle::ZERO.chord_length(2.0), 0.0);
+    }
+
+    #[test]
+    fn test_central_angle_from_chord() {
+        let angle = Angle::central_angle_from_chord(4.0, 2.0).unwrap();
+        assert!(angle.abs_diff(Angle::HALF_TURN).repr() <= 1 << 12);
+        assert!((angle.chord_length(2.0) - 4.0).abs() < 1e-9);
+
+        let angle = Angle::central_angle_from_chord(1.0, 1.0).unwrap();
+        assert!((angle.as_degrees() - 60.0).abs() < 1e-9);
+        assert!((angle.chord_length(1.0) - 1.0).abs() < 1e-4);
+
+        assert_eq!(Angle::central_angle_from_chord(4.1, 2.0), None);
+        assert_eq!(Angle::central_angle_from_chord(-1.0, 2.0), None);
+        assert_eq!(Angle::central_angle_from_chord(1.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_sin_rate_per_turn() {
+        assert_eq!(Angle::ZERO.sin_rate_per_turn(), core::f64::consts::TAU);
+        assert_eq!(Angle::QUARTER_TURN.sin_rate_per_turn(), 0.0);
+        assert_eq!(
+            Angle::HALF_TURN.sin_rate_per_turn(),
+            -core::f64::consts::TAU
+        );
+    }
+
+    #[test]
+    fn test_excsc() {
+        assert_eq!(Angle::from_degrees_exact_int(90).excsc(), 0.0);
+        assert_eq!(Angle::from_repr(0).excsc(), f64::INFINITY);
+
+        let excsc = Angle::from_degrees_exact_int(30).excsc();
+        assert!((excsc - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_percent_grade() {
+        let angle = Angle::from_percent_grade(100.0).unwrap();
+        assert!((angle.as_degrees() - 45.0).abs() < 1e-9);
+        assert!((angle.as_percent_grade() - 100.0).abs() < 1e-2);
+
+        assert_eq!(Angle::from_percent_grade(0.0), Some(Angle::from_repr(0)));
+        assert_eq!(Angle::from_repr(0).as_percent_grade(), 0.0);
+        assert_eq!(Angle::from_percent_grade(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_percent_grade_vertical() {
+        let vertical = Angle::from_degrees_exact_int(90);
+
+        assert_eq!(Angle::from_percent_grade(f64::INFINITY), Some(vertical));
+        assert_eq!(vertical.as_percent_grade(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_wrap_into_signed_degrees_range() {
+        let wrap = |degrees, lo, hi| {
+            Angle::from_degrees_exact_int(degrees).wrap_into_signed_degrees_range(lo, hi)
+        };
+        let assert_wraps = |degrees, lo, expected: f64| {
+            let wrapped = wrap(degrees, lo, lo + 360.0).unwrap();
+            assert!((wrapped - expected).abs() < 1e-9, "{wrapped} != {expected}");
+        };
+
+        assert_wraps(200, -90.0, 200.0);
+        assert_wraps(300, -90.0, -60.0);
+        assert_wraps(270, -90.0, -90.0);
+        assert_wraps(90, 360.0, 450.0);
+        assert_wraps(0, -180.0, 0.0);
+
+        assert_eq!(wrap(0, 0.0, 180.0), None);
+        assert_eq!(wrap(0, f64::NAN, 360.0), None);
+        assert_eq!(wrap(0, f64::INFINITY, f64::INFINITY), None);
+    }
+
+    #[test]
+    fn test_from_sin_cos() {
+        let angle = Angle::from_sin_cos(1.0, 0.0).unwrap();
+        assert_eq!(angle.as_degrees(), 90.0);
+
+        let angle = Angle::from_sin_cos(-1.0, 0.0).unwrap();
+        assert_eq!(angle.as_degrees(), 270.0);
+    }
+
+    #[test]
+    fn test_sin_cos_round_trip() {
+        let angle = Angle::from_degrees(200.0).unwrap();
+        let (sin, cos) = angle.sin_cos();
+        let recovered = Angle::from_sin_cos(sin, cos).unwrap();
+
+        assert!((recovered.as_degrees() - 200.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_from_degrees_report() {
+        let (angle, residual) = Angle::from_degrees_report(90.0).unwrap();
+        assert_eq!(angle, Angle::from_degrees_exact_int(90));
+        assert_eq!(residual, 0.0);
+
+        // A third of a turn isn't exactly representable, and the conversion
+        // truncates, so the residual is a tiny positive value
+        let (angle, residual) = Angle::from_degrees_report(120.0).unwrap();
+        let units = (u64::MAX / 3 - angle.repr()) as f64 + 1.0 / 3.0;
+        let expected = units * 360.0 / 2.0f64.powi(64);
+        assert!(residual > 0.0);
+        assert!((residual - expected).abs() < 1e-17);
+
+        let (_, residual) = Angle::from_degrees_report(-240.0).unwrap();
+        assert!(residual.abs() < 1e-12);
+        assert!(residual != 0.0);
+
+        let (_, residual) = Angle::from_degrees_report(720.0 + 45.0).unwrap();
+        assert_eq!(residual, 0.0);
+
+        assert_eq!(Angle::from_degrees_report(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_from_degrees_report_tiny() {
+        for degrees in [1e-10, 3e-15, 1e-300] {
+            let (angle, residual) = Angle::from_degrees_report(degrees).unwrap();
+
+            // as_degrees is itself only accurate to about one unit of repr
+            let approx = degrees - angle.as_degrees();
+            assert!(
+                (residual - approx).abs() < Angle::resolution_degrees(),
+                "{degrees}"
+            );
+
+            // For small reprs, this computation is accurate to the last bit
+            let exact = degrees - angle.repr() as f64 * 360.0 / 2.0f64.powi(64);
+            assert!((residual - exact).abs() <= degrees * 1e-15, "{degrees}");
+        }
+    }
+
+    #[test]
+    fn test_from_degrees_checked() {
+        let (angle, wrapped) = Angle::from_degrees_checked(450.0).unwrap();
+        assert_eq!(angle.as_degrees(), 90.0);
+        assert!(wrapped);
+
+        let (angle, wrapped) = Angle::from_degrees_checked(45.0).unwrap();
+        assert_eq!(angle.as_degrees(), 45.0);
+        assert!(!wrapped);
+
+        let (_, wrapped) = Angle::from_degrees_checked(-10.0).unwrap();
+        assert!(wrapped);
+
+        assert_eq!(Angle::from_degrees_checked(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_degrees_minutes_round_trip() {
+        let angle = Angle::from_degrees_minutes(40, 26.767).unwrap();
+        let (degrees, minutes) = angle.to_degrees_minutes();
+
+        assert_eq!(degrees, 40);
+        assert!((minutes - 26.767).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_degrees_minutes_negative() {
+        let angle = Angle::from_degrees_minutes(-40, 30.0).unwrap();
+        let (degrees, minutes) = angle.to_degrees_minutes();
+
+        assert_eq!(degrees, 319);
+        assert!((minutes - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_signed_dms_round_trip() {
+        let angle = Angle::from_signed_dms(Sign::Negative, 30, 15, 0.0).unwrap();
+        let degrees = crate::SignedAngle::from_angle(angle).as_degrees();
+        assert!((degrees + 30.25).abs() < 1e-9);
+
+        let (sign, degrees, minutes, seconds) = angle.to_signed_dms();
+        let total = f64::from(degrees) + f64::from(minutes) / 60.0 + seconds / 3600.0;
+        assert_eq!(sign, Sign::Negative);
+        assert!((total - 30.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_signed_dms_exact() {
+        let angle = Angle::from_signed_dms(Sign::Negative, 45, 0, 0.0).unwrap();
+        assert_eq!(angle, Angle::from_degrees_exact_int(-45));
+        assert_eq!(angle.to_signed_dms(), (Sign::Negative, 45, 0, 0.0));
+
+        let angle = Angle::from_signed_dms(Sign::Positive, 22, 30, 0.0).unwrap();
+        assert_eq!(angle.to_signed_dms(), (Sign::Positive, 22, 30, 0.0));
+
+        assert_eq!(Angle::from_signed_dms(Sign::Positive, 0, 0, f64::NAN), None);
+    }
+
+    #[test]
+    fn test_approx_rational_turn() {
+        let angle = Angle::from_rotations(1.0 / 7.0 + 1e-6).unwrap();
+        let tolerance = Angle::from_degrees(0.01).unwrap();
+
+        assert_eq!(angle.approx_rational_turn(tolerance), Some((1, 7)));
+        assert_eq!(angle.approx_rational_turn(Angle::ZERO), None);
+
+        let third = Angle::from_ratio(2, 3).unwrap();
+        assert_eq!(third.approx_rational_turn(Angle::ZERO), Some((2, 3)));
+        assert_eq!(
+            Angle::QUARTER_TURN.approx_rational_turn(Angle::ZERO),
+            Some((1, 4))
+        );
+        assert_eq!(
+            Angle::ZERO.prev().approx_rational_turn(tolerance),
+            Some((0, 1))
+        );
+    }
+
+    #[test]
+    fn test_approx_rational_turn_irrational() {
+        let angle = Angle::from_rotations(core::f64::consts::SQRT_2 - 1.0).unwrap();
+
+        assert_eq!(angle.approx_rational_turn(Angle::from_repr(1 << 20)), None);
+        assert_eq!(
+            angle.approx_rational_turn(Angle::from_ratio(1, 20).unwrap()),
+            Some((2, 5))
+        );
+    }
+
+    #[test]
+    fn test_scaled_parts() {
+        let angle = Angle::from_ratio(61, 720).unwrap();
+        assert_eq!(angle.as_scaled_parts(360, 60), (30, 30));
+        assert_eq!(
+            Angle::from_degrees(30.5).unwrap().as_scaled_parts(360, 60),
+            (30, 30)
+        );
+
+        assert_eq!(Angle::QUARTER_TURN.as_scaled_parts(24, 60), (6, 0));
+        assert_eq!(
+            Angle::from_ratio(1, 3).unwrap().as_scaled_parts(400, 100),
+            (133, 33)
+        );
+    }
+
+    #[test]
+    fn test_scaled_parts_carry() {
+        let angle = Angle::from_degrees_exact_int(45).prev();
+        assert_eq!(angle.as_scaled_parts(360, 60), (45, 0));
+
+        assert_eq!(Angle::ZERO.prev().as_scaled_parts(360, 60), (0, 0));
+        assert_eq!(Angle::HALF_TURN.prev().as_scaled_parts(2, 0), (0, 0));
+    }
+
+    #[test]
+    fn test_rotation_matrix_round_trip() {
+        let angle = Angle::from_degrees(30.0).unwrap();
+        let matrix = angle.to_rotation_matrix();
+        let recovered = Angle::from_rotation_matrix(matrix).unwrap();
+
+        assert!((recovered.as_degrees() - 30.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_rotation_matrix_ignores_scale() {
+        let recovered = Angle::from_rotation_matrix([[0.0, -3.0], [3.0, 0.0]]).unwrap();
+        assert_eq!(recovered.as_degrees(), 90.0);
+    }
+
+    #[test]
+    fn test_as_bam_scaled() {
+        let angle = Angle::from_degrees(90.0).unwrap();
+
+        assert_eq!(angle.as_bam_scaled(2), Some(1));
+        assert_eq!(angle.as_bam_scaled(20), Some(1 << 18));
+        assert_eq!(angle.as_bam_scaled(64), Some(angle.repr()));
+        assert_eq!(angle.as_bam_scaled(0), Some(0));
+        assert_eq!(angle.as_bam_scaled(65), None);
+    }
+
+    #[test]
+    fn test_from_bam_scaled() {
+        let angle = Angle::from_degrees(270.0).unwrap();
+
+        assert_eq!(Angle::from_bam_scaled(3, 2), Some(angle));
+        assert_eq!(Angle::from_bam_scaled(7, 2), Some(angle));
+        assert_eq!(Angle::from_bam_scaled(3 << 18, 20), Some(angle));
+        assert_eq!(Angle::from_bam_scaled(3, 65), None);
+    }
+
+    #[test]
+    fn test_half_exact_ratio() {
+        let angle = Angle::from_repr(0x80_00_00_00_00_00_00_00);
+        assert_eq!(angle.as_exact_ratio(), (0x80_00_00_00_00_00_00_00, 1 << 64))
+    }
+}
+
+#[cfg(test)]
+mod sign_tests {
+    use super::Angle;
+    use crate::sign::Sign::{Negative, Positive};
+
+    #[test]
+    fn test_zero_outputs_are_positive_zero() {
+        // sin and cos are zero where their sign flips, but the result is
+        // always +0.0, never -0.0, regardless of the side it's approached from
+        for repr in [0, 1 << 62, 1 << 63, 3 << 62] {
+            let angle = Angle::from_repr(repr);
+            let (sin, cos) = angle.sin_cos();
+
+            for value in [sin, cos] {
+                assert!(value != 0.0 || value.is_sign_positive(), "{repr:#x}");
+            }
+        }
+
+        assert!(Angle::from_repr(1 << 63).sin().is_sign_positive());
+        assert!(Angle::from_repr(1 << 62).cos().is_sign_positive());
+        assert!(Angle::from_repr(3 << 62).cos().is_sign_positive());
+        assert_eq!(Angle::from_repr(1 << 62).tan(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_cardinal_signs() {
+        let cases = [
+            (0x00_00_00_00_00_00_00_00, Positive, Positive),
+            (0x40_00_00_00_00_00_00_00, Positive, Negative),
+            (0x80_00_00_00_00_00_00_00, Negative, Negative),
+            (0xC0_00_00_00_00_00_00_00, Negative, Positive),
+        ];
+
+        for (repr, sin, cos) in cases {
+            let angle = Angle::from_repr(repr);
+            assert_eq!(angle.sin_sign(), sin, "sin sign of {repr:#x}");
+            assert_eq!(angle.cos_sign(), cos, "cos sign of {repr:#x}");
+        }
+    }
+
+    #[test]
+    fn test_mid_quadrant_signs() {
+        let cases = [
+            (45.0, Positive, Positive),
+            (135.0, Positive, Negative),
+            (225.0, Negative, Negative),
+            (315.0, Negative, Positive),
+        ];
+
+        for (degrees, sin, cos) in cases {
+            let angle = Angle::from_degrees(degrees).unwrap();
+            assert_eq!(angle.sin_sign(), sin, "sin sign of {degrees}");
+            assert_eq!(angle.cos_sign(), cos, "cos sign of {degrees}");
+            assert_eq!(angle.sin() < 0.0, sin == Negative);
+            assert_eq!(angle.cos() < 0.0, cos == Negative);
+        }
+    }
+
+    #[test]
+    fn test_reduce_to_quadrant() {
+        let (quadrant, offset) = Angle::from_degrees(200.0).unwrap().reduce_to_quadrant();
+        assert_eq!(quadrant, 2);
+        assert!((offset.as_degrees() - 20.0).abs() < 1e-9);
+
+        let (quadrant, offset) = Angle::from_degrees(270.0).unwrap().reduce_to_quadrant();
+        assert_eq!(quadrant, 3);
+        assert_eq!(offset, Angle::from_repr(0));
+    }
+
+    #[test]
+    fn test_half_turn_fold() {
+        let (sign, folded) = Angle::from_degrees_exact_int(200).half_turn_fold();
+        assert_eq!(sign, Negative);
+        assert_eq!(folded, Angle::from_degrees_exact_int(20));
+
+        let angle = Angle::from_degrees_exact_int(160);
+        assert_eq!(angle.half_turn_fold(), (Positive, angle));
+
+        let (sign, folded) = Angle::from_repr(1 << 63).half_turn_fold();
+        assert_eq!(sign, Negative);
+        assert_eq!(folded, Angle::from_repr(0));
+    }
+
+    #[test]
+    fn test_fold_period() {
+        let expected = Angle::from_degrees_exact_int(10);
+
+        for degrees in [10, 100, 190, 280] {
+            let folded = Angle::from_degrees_exact_int(degrees)
+                .fold_period(4)
+                .unwrap();
+            assert!(folded.abs_diff(expected).repr() <= 1, "{degrees}°");
+        }
+
+        let angle = Angle::from_degrees_exact_int(250);
+        assert_eq!(angle.fold_period(1), Some(angle));
+        assert_eq!(
+            angle.fold_period(2),
+            Some(Angle::from_degrees_exact_int(70))
+        );
+        assert_eq!(angle.fold_period(0), None);
+    }
+
+    #[test]
+    fn test_octant_signs() {
+        for octant in 0..8 {
+            let degrees = 22.5 + 45.0 * f64::from(octant);
+            let angle = Angle::from_degrees(degrees).unwrap();
+            let (found, sin, cos) = angle.octant_signs();
+
+            assert_eq!(found, octant, "octant of {degrees}");
+            assert_eq!(sin, angle.sin_sign());
+            assert_eq!(cos, angle.cos_sign());
+            assert_eq!(angle.sin() < 0.0, sin == Negative, "sin sign of {degrees}");
+            assert_eq!(angle.cos() < 0.0, cos == Negative, "cos sign of {degrees}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod bisector_tests {
+    use super::{Angle, BisectorSide};
+
+    fn assert_near(angle: Angle, expected: Angle) {
+        let error = angle.repr().wrapping_sub(expected.repr()) as i64;
+        assert!(
+            error.unsigned_abs() < 1 << 16,
+            "{angle:?} is not near {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_internal_bisector_wraps() {
+        let a = Angle::from_degrees(10.0).unwrap();
+        let b = Angle::from_degrees(350.0).unwrap();
+
+        assert_near(a.bisector(b, BisectorSide::Internal), Angle::from_repr(0));
+        assert_near(b.bisector(a, BisectorSide::Internal), Angle::from_repr(0));
+    }
+
+    #[test]
+    fn test_external_bisector_wraps() {
+        let a = Angle::from_degrees(10.0).unwrap();
+        let b = Angle::from_degrees(350.0).unwrap();
+        let half = Angle::from_repr(0x80_00_00_00_00_00_00_00);
+
+        assert_near(a.bisector(b, BisectorSide::External), half);
+        assert_near(b.bisector(a, BisectorSide::External), half);
+    }
+
+    #[test]
+    fn test_bisector_exact() {
+        let a = Angle::from_repr(0x10_00_00_00_00_00_00_00);
+        let b = Angle::from_repr(0x30_00_00_00_00_00_00_00);
+
+        assert_eq!(
+            a.bisector(b, BisectorSide::Internal),
+            Angle::from_repr(0x20_00_00_00_00_00_00_00)
+        );
+        assert_eq!(
+            a.bisector(b, BisectorSide::External),
+            Angle::from_repr(0xA0_00_00_00_00_00_00_00)
+        );
+    }
+
+    #[test]
+    fn test_bisect() {
+        let angle = Angle::from_degrees_exact_int(60);
+
+        assert_eq!(angle.bisect(), Angle::from_degrees_exact_int(30));
+        assert_eq!(angle.bisect_reflex(), Angle::from_degrees_exact_int(210));
+    }
+}
+
+#[cfg(test)]
+mod error_bound_tests {
+    use super::Angle;
+
+    // The lookup table divides a quarter turn into 256 zones, so each zone is
+    // 2^54 units of repr wide.
+    const ZONE: u64 = 1 << 54;
+
+    #[test]
+    fn test_sin_plus_small() {
+        for degrees in (0..360).step_by(15) {
+            let base = Angle::from_degrees_exact_int(degrees);
+
+            for epsilon in [0.01, -0.01, 0.1, -0.1] {
+                let epsilon = Angle::from_degrees(epsilon).unwrap();
+                let approx = base.sin_plus_small(epsilon);
+                let exact = (base + epsilon).sin();
+
+                assert!((approx - exact).abs() < 1e-4, "{degrees}° + {epsilon:?}");
+            }
+        }
+    }
+
+    fn reference_sin(angle: Angle) -> f64 {
+        libm::sin(angle.repr() as f64 / 2f64.powi(64) * core::f64::consts::TAU)
+    }
+
+    #[test]
+    fn test_sin_quadratic_accuracy() {
+        for i in 0..4096u64 {
+            let angle = Angle::from_repr(i.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+            let error = (angle.sin_quadratic() - reference_sin(angle)).abs();
+
+            assert!(error < 2e-8, "{angle:?}: {error}");
+        }
+
+        assert_eq!(Angle::QUARTER_TURN.sin_quadratic(), 1.0);
+        assert_eq!(Angle::HALF_TURN.sin_quadratic(), 0.0);
+        assert_eq!(Angle::from_repr(3 << 62).sin_quadratic(), -1.0);
+    }
+
+    #[test]
+    fn test_sin_quadratic_beats_sin_mid_zone() {
+        for zone in [1, 16, 100, 200, 255] {
+            let angle = Angle::from_repr(zone * ZONE + ZONE / 2);
+            let reference = reference_sin(angle);
+
+            let linear_error = (angle.sin() - reference).abs();
+            let quadratic_error = (angle.sin_quadratic() - reference).abs();
+
+            assert!(quadratic_error < linear_error, "zone {zone}");
+        }
+    }
+
+    #[test]
+    fn test_table_aligned_is_smaller() {
+        for zone in [0, 1, 16, 100, 255] {
+            let aligned = Angle::from_repr(zone * ZONE);
+            let mid_zone = Angle::from_repr(zone * ZONE + ZONE / 2);
+
+            assert!(
+                aligned.sin_error_bound() < mid_zone.sin_error_bound(),
+                "zone {zone}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_aligned_is_tiny() {
+        let angle = Angle::from_repr(16 * ZONE);
+        assert!(angle.sin_error_bound() <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_quarter_is_exact() {
+        let angle = Angle::from_repr(0x40_00_00_00_00_00_00_00);
+        assert_eq!(angle.sin_error_bound(), 0.0);
+    }
+
+    #[test]
+    fn test_symmetric() {
+        let angle = Angle::from_repr(3 * ZONE + ZONE / 3);
+        let reflected = Angle::from_repr(0u64.wrapping_sub(angle.repr()));
+
+        assert_eq!(angle.sin_error_bound(), reflected.sin_error_bound());
+    }
+}
+
+#[cfg(test)]
+mod step_iter_tests {
+    use super::Angle;
+
+    #[test]
+    fn test_quarter_steps() {
+        let quarter = Angle::from_degrees(90.0).unwrap();
+        let mut steps = Angle::step_iter(Angle::from_repr(0), quarter);
+
+        for expected in [0.0, 90.0, 180.0, 270.0] {
+            assert_eq!(steps.next().unwrap().as_degrees(), expected);
+        }
+
+        assert_eq!(steps.next(), None);
+    }
+
+    #[test]
+    fn test_uneven_steps() {
+        let start = Angle::from_degrees(10.0).unwrap();
+        let step = Angle::from_repr(0x60_00_00_00_00_00_00_00);
+        let steps: [u64; 3] = [0, 0x60_00_00_00_00_00_00_00, 0xC0_00_00_00_00_00_00_00];
+
+        let mut found = Angle::step_iter(start, step);
+
+        for offset in steps {
+            let angle = found.next().unwrap();
+            assert_eq!(angle.repr(), start.repr().wrapping_add(offset));
+        }
+
+        assert_eq!(found.next(), None);
+    }
+
+    #[test]
+    fn test_zero_step() {
+        let start = Angle::from_degrees(45.0).unwrap();
+        let mut steps = Angle::step_iter(start, Angle::from_repr(0));
+
+        assert_eq!(steps.next(), Some(start));
+        assert_eq!(steps.next(), None);
+    }
+}
+
+#[cfg(test)]
+mod fixed_point_tests {
+    use super::Angle;
+
+    #[test]
+    fn test_facing_u8() {
+        assert_eq!(Angle::from_degrees_exact_int(90).to_facing_u8(), 64);
+        assert_eq!(Angle::from_facing_u8(64), Angle::QUARTER_TURN);
+        assert_eq!(Angle::from_degrees(359.0).unwrap().to_facing_u8(), 255);
+
+        for facing in 0..=255 {
+            assert_eq!(Angle::from_facing_u8(facing).to_facing_u8(), facing);
+        }
+    }
+
+    #[test]
+    fn test_repr_bytes() {
+        let bytes = [0x80, 0, 0, 0, 0, 0, 0, 0];
+
+        assert_eq!(Angle::from_repr_bytes(&bytes), Angle::HALF_TURN);
+        assert_eq!(Angle::HALF_TURN.to_repr_bytes(), bytes);
+
+        let angle = Angle::from_repr(0x0123_4567_89AB_CDEF);
+        assert_eq!(Angle::from_repr_bytes(&angle.to_repr_bytes()), angle);
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let stream = [0x40, 0, 0, 0, 0, 0, 0, 0, 0xFF];
+
+        assert_eq!(Angle::try_from_slice(&stream), Some(Angle::QUARTER_TURN));
+        assert_eq!(
+            Angle::try_from_slice(&stream[1..]),
+            Some(Angle::from_repr(0xFF))
+        );
+        assert_eq!(Angle::try_from_slice(&stream[..7]), None);
+        assert_eq!(Angle::try_from_slice(&[]), None);
+    }
+
+    #[test]
+    fn test_sin_q16_cardinals() {
+        let cases = [
+            (0x00_00_00_00_00_00_00_00, 0),
+            (0x40_00_00_00_00_00_00_00, 65536),
+            (0x80_00_00_00_00_00_00_00, 0),
+            (0xC0_00_00_00_00_00_00_00, -65536),
+        ];
+
+        for (repr, expected) in cases {
+            assert_eq!(Angle::from_repr(repr).sin_q16(), expected, "{repr:#x}");
+        }
+    }
+
+    #[test]
+    fn test_sin_cos_q31_cardinals() {
+        let cases = [
+            (0, (0, i32::MAX)),
+            (90, (i32::MAX, 0)),
+            (180, (0, -i32::MAX)),
+            (270, (-i32::MAX, 0)),
+        ];
+
+        for (degrees, expected) in cases {
+            let angle = Angle::from_degrees_exact_int(degrees);
+            assert_eq!(angle.sin_cos_q31(), expected, "{degrees}°");
+        }
+    }
+
+    #[test]
+    fn test_sin_cos_q31_matches_float() {
+        let scale = 2f64.powi(31);
+
+        for degrees in [10.0, 30.0, 89.0, 91.0, 200.0, 300.0] {
+            let angle = Angle::from_degrees(degrees).unwrap();
+            let (sin, cos) = angle.sin_cos_q31();
+
+            assert!(
+                (f64::from(sin) - angle.sin() * scale).abs() <= 1.0,
+                "{degrees}"
+            );
+            assert!(
+                (f64::from(cos) - angle.cos() * scale).abs() <= 1.0,
+                "{degrees}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sin_q16_matches_float() {
+        for degrees in [10.0, 30.0, 89.0, 91.0, 200.0, 300.0] {
+            let angle = Angle::from_degrees(degrees).unwrap();
+            let expected = angle.sin() * 65536.0;
+
+            assert!(
+                (f64::from(angle.sin_q16()) - expected).abs() <= 0.5,
+                "{degrees}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_q31() {
+        assert_eq!(Angle::from_degrees_exact_int(90).to_q31(), 0x4000_0000);
+        assert_eq!(Angle::from_degrees_exact_int(270).to_q31(), -0x4000_0000);
+        assert_eq!(Angle::from_repr(1 << 63).to_q31(), i32::MIN);
+
+        for q31 in [0, 1, -1, 0x1234_5678, i32::MIN, i32::MAX] {
+            assert_eq!(Angle::from_q31(q31).to_q31(), q31);
+        }
+    }
+}
+
+#[cfg(test)]
+mod exact_tests {
+    use super::{Angle, RoundMode};
+
+    #[test]
+    fn test_from_rotations_f32_exact() {
+        assert_eq!(
+            Angle::from_rotations_f32_exact(0.5),
+            Some(Angle::from_repr(1 << 63))
+        );
+        assert!(Angle::from_rotations_f32_exact(0.1).is_some());
+        assert_eq!(
+            Angle::from_rotations_f32_exact(0.0),
+            Some(Angle::from_repr(0))
+        );
+
+        assert_eq!(Angle::from_rotations_f32_exact(1.5), None);
+        assert_eq!(Angle::from_rotations_f32_exact(-0.25), None);
+        assert_eq!(Angle::from_rotations_f32_exact(1e-30), None);
+        assert_eq!(Angle::from_rotations_f32_exact(f32::NAN), None);
+    }
+
+    #[test]
+    fn test_from_rotations_with() {
+        let tiny = 1e-30;
+
+        assert_eq!(Angle::from_rotations(tiny), Some(Angle::from_repr(0)));
+        assert_eq!(
+            Angle::from_rotations_with(tiny, RoundMode::Ceil),
+            Some(Angle::from_repr(1))
+        );
+        assert_eq!(
+            Angle::from_rotations_with(1.0 / 3.0, RoundMode::Nearest),
+            Angle::from_rotations(1.0 / 3.0)
+        );
+    }
+
+    #[test]
+    fn test_signed_repr() {
+        let angle = Angle::from_degrees_exact_int(270);
+
+        assert_eq!(angle.as_signed_repr(), -(1 << 62));
+        assert_eq!(Angle::from_signed_repr(angle.as_signed_repr()), angle);
+        assert_eq!(Angle::from_degrees_exact_int(90).as_signed_repr(), 1 << 62);
+        assert_eq!(Angle::from_repr(1 << 63).as_signed_repr(), i64::MIN);
+    }
+
+    #[test]
+    fn test_prev_next() {
+        assert_eq!(Angle::ZERO.prev().repr(), u64::MAX);
+        assert_eq!(Angle::ZERO.next().repr(), 1);
+        assert_eq!(Angle::ZERO.prev().next(), Angle::ZERO);
+
+        let angle = Angle::from_degrees_exact_int(30);
+        assert!(angle.prev().repr() < angle.repr());
+        assert_eq!(angle.next().prev(), angle);
+    }
+
+    #[test]
+    fn test_from_ratio() {
+        assert_eq!(Angle::from_ratio(1, 4), Some(Angle::from_repr(1 << 62)));
+        assert_eq!(Angle::from_ratio(5, 4), Some(Angle::from_repr(1 << 62)));
+        assert_eq!(Angle::from_ratio(1, 3).unwrap().repr(), u64::MAX / 3);
+        assert_eq!(Angle::from_ratio(1, 0), None);
+    }
+
+    #[test]
+    fn test_from_degrees_exact_int() {
+        assert_eq!(Angle::from_degrees_exact_int(90), Angle::from_repr(1 << 62));
+        assert_eq!(
+            Angle::from_degrees_exact_int(-270),
+            Angle::from_repr(1 << 62)
+        );
+        assert_eq!(Angle::from_degrees_exact_int(720), Angle::from_repr(0));
+    }
+
+    #[test]
+    fn test_from_radians_precise() {
+        // Exact reductions, computed with arbitrary precision arithmetic
+        let cases = [
+            (1.0, 0x28BE_60DB_9391_054A),
+            (1e6, 0xF16E_786F_96FB_F8F3),
+            (1e22, 0xD66F_29EF_84BC_90CC),
+            (2.0f64.powi(100), 0xD4D3_7703_6D8A_5664),
+            (-1e6, 0x0E91_8790_6904_070C),
+            (1e-20, 0),
+            (f64::MIN_POSITIVE, 0),
+        ];
+
+        for (radians, expected) in cases {
+            let angle = Angle::from_radians_precise(radians).unwrap();
+            let error = angle.abs_diff(Angle::from_repr(expected)).repr();
+
+            assert!(error < 2, "{radians} is off by {error}");
+        }
+    }
+
+    #[test]
+    fn test_from_radians_precise_matches_float() {
+        for i in 0..1000 {
+            let radians = f64::from(i) * 1013.7;
+            let precise = Angle::from_radians_precise(radians).unwrap();
+            let float = Angle::from_radians(radians).unwrap();
+
+            // The float version is only accurate to around `radians * 2^-53`
+            assert!(precise.abs_diff(float).as_radians() < 1e-9, "{radians}");
+        }
+    }
+
+    #[test]
+    fn test_from_radians_precise_range() {
+        assert_eq!(Angle::from_radians_precise(0.0), Some(Angle::from_repr(0)));
+
+        // The float PI is slightly less than the true value of pi, so unlike
+        // with `from_radians`, this is (very slightly) less than a half turn
+        let angle = Angle::from_radians_precise(core::f64::consts::PI).unwrap();
+        assert!(angle.repr() < 1 << 63);
+        assert!(angle.abs_diff(Angle::from_repr(1 << 63)).repr() < 1 << 10);
+
+        assert!(Angle::from_radians_precise(2.0f64.powi(180)).is_some());
+        assert_eq!(Angle::from_radians_precise(2.0f64.powi(181)), None);
+        assert_eq!(Angle::from_radians_precise(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_from_radians_turns_split() {
+        let half = Some(Angle::from_repr(1 << 63));
+
+        assert_eq!(
+            Angle::from_radians_turns_split(1_000_000, core::f64::consts::PI),
+            half
+        );
+        assert_eq!(
+            Angle::from_radians_turns_split(i64::MIN, core::f64::consts::PI),
+            half
+        );
+        assert_eq!(Angle::from_radians_turns_split(0, f64::NAN), None);
+    }
+
+    #[test]
+    fn test_polygon_angles() {
+        assert_eq!(
+            Angle::interior_angle(6),
+            Some(Angle::from_degrees_exact_int(120))
+        );
+        assert_eq!(
+            Angle::exterior_angle(6),
+            Some(Angle::from_degrees_exact_int(60))
+        );
+        assert_eq!(
+            Angle::interior_angle(4),
+            Some(Angle::from_degrees_exact_int(90))
+        );
+
+        assert_eq!(Angle::interior_angle(2), None);
+        assert_eq!(Angle::exterior_angle(0), None);
+    }
+
+    #[test]
+    fn test_quantize_uniform() {
+        let deg_90 = Some(Angle::from_degrees_exact_int(90));
+
+        assert_eq!(
+            Angle::from_degrees_exact_int(80).quantize_uniform(4),
+            deg_90
+        );
+        assert_eq!(
+            Angle::from_degrees_exact_int(100).quantize_uniform(4),
+            deg_90
+        );
+        assert_eq!(
+            Angle::from_degrees_exact_int(350).quantize_uniform(4),
+            Some(Angle::from_repr(0))
+        );
+        assert_eq!(
+            Angle::from_degrees_exact_int(100).quantize_uniform(1),
+            Some(Angle::from_repr(0))
+        );
+        assert_eq!(Angle::from_degrees_exact_int(80).quantize_uniform(0), None);
+    }
+
+    #[test]
+    fn test_is_multiple_of() {
+        let deg_30 = Angle::from_degrees_exact_int(30);
+        let deg_45 = Angle::from_degrees_exact_int(45);
+        let deg_90 = Angle::from_degrees_exact_int(90);
+        let deg_270 = Angle::from_degrees_exact_int(270);
+
+        assert!(deg_90.is_multiple_of(deg_30));
+        assert!(deg_270.is_multiple_of(deg_30));
+        assert!(deg_90.is_multiple_of(deg_45));
+        assert!(deg_270.is_multiple_of(deg_90));
+        assert!(!deg_45.is_multiple_of(deg_30));
+        assert!(!deg_30.is_multiple_of(deg_90));
+        assert!(!deg_45.is_multiple_of(deg_90));
+    }
+
+    #[test]
+    fn test_is_multiple_of_inexact_divisors() {
+        for divisor in [7, 11, 30, 36, 72, 120] {
+            let step = Angle::from_degrees_exact_int(divisor);
+
+            for k in 0..360 / divisor {
+                let angle = Angle::from_degrees_exact_int(divisor * k);
+                assert!(angle.is_multiple_of(step), "{} of {divisor}", divisor * k);
+
+                // Just past the allowance for truncation
+                let past = Angle::from_repr(angle.repr() + k as u64 + 1);
+                assert!(!past.is_multiple_of(step), "{} of {divisor}", divisor * k);
+            }
+
+            let between = Angle::from_degrees_exact_int(divisor / 2 + 1);
+            assert!(!between.is_multiple_of(step), "{divisor}");
+        }
+    }
+
+    #[test]
+    fn test_is_multiple_of_zero() {
+        let zero = Angle::from_repr(0);
+
+        assert!(zero.is_multiple_of(zero));
+        assert!(zero.is_multiple_of(Angle::from_degrees_exact_int(30)));
+        assert!(!Angle::from_degrees_exact_int(30).is_multiple_of(zero));
+    }
+
+    #[test]
+    fn test_try_as_degrees_exact_round_trip() {
+        for degrees in 0..360 {
+            let angle = Angle::from_degrees_exact_int(degrees);
+            assert_eq!(angle.try_as_degrees_exact(), Some(degrees as i32));
+        }
+    }
+
+    #[test]
+    fn test_try_as_degrees_exact_inexact() {
+        let angle = Angle::from_degrees_exact_int(90);
+        assert_eq!(angle.try_as_degrees_exact(), Some(90));
+
+        let angle = Angle::from_radians(core::f64::consts::FRAC_PI_6).unwrap();
+        assert_eq!(angle.try_as_degrees_exact(), None);
+
+        let angle = Angle::from_repr(Angle::from_degrees_exact_int(30).repr() + 1);
+        assert_eq!(angle.try_as_degrees_exact(), None);
+    }
+}
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod arbitrary_tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use super::Angle;
+
+    #[test]
+    fn test_arbitrary_sin_in_range() {
+        let data: [u8; 64] = core::array::from_fn(|i| (i as u8).wrapping_mul(97));
+        let mut unstructured = Unstructured::new(&data);
+
+        for _ in 0..8 {
+            let angle = Angle::arbitrary(&mut unstructured).unwrap();
+            let sin = angle.sin();
+
+            assert!((-1.0..=1.0).contains(&sin), "{angle:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod distance_tests {
+    use super::Angle;
+    use crate::{direction::Direction, sign::Sign};
+
+    fn degrees(degrees: f64) -> Angle {
+        Angle::from_degrees(degrees).unwrap()
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        let a = Angle::from_repr(0x10_00_00_00_00_00_00_00);
+        let b = Angle::from_repr(0xF0_00_00_00_00_00_00_00);
+        let expected = Angle::from_repr(0x20_00_00_00_00_00_00_00);
+
+        assert_eq!(a.abs_diff(b), expected);
+        assert_eq!(b.abs_diff(a), expected);
+    }
+
+    #[test]
+    fn test_abs_diff_half() {
+        let a = Angle::from_repr(0x10_00_00_00_00_00_00_00);
+        let b = Angle::from_repr(0x90_00_00_00_00_00_00_00);
+        let half = Angle::from_repr(0x80_00_00_00_00_00_00_00);
+
+        assert_eq!(a.abs_diff(b), half);
+        assert_eq!(b.abs_diff(a), half);
+    }
+
+    #[test]
+    fn test_closest_of() {
+        let candidates = [degrees(0.0), degrees(90.0), degrees(180.0)];
+
+        assert_eq!(
+            degrees(80.0).closest_of(&candidates),
+            Some((1, candidates[1]))
+        );
+        assert_eq!(
+            degrees(350.0).closest_of(&candidates),
+            Some((0, candidates[0]))
+        );
+        assert_eq!(degrees(80.0).closest_of(&[]), None);
+    }
+
+    #[test]
+    fn test_closest_of_tie() {
+        let candidates = [
+            Angle::from_degrees_exact_int(90),
+            Angle::from_degrees_exact_int(270),
+        ];
+
+        let angle = Angle::from_repr(0);
+        assert_eq!(angle.closest_of(&candidates), Some((0, candidates[0])));
+    }
+
+    #[test]
+    fn test_clamp_rate() {
+        let max_rate = Angle::from_degrees_exact_int(10);
+        let previous = Angle::ZERO;
+
+        assert_eq!(
+            Angle::from_degrees_exact_int(90).clamp_rate(previous, max_rate),
+            max_rate
+        );
+        assert_eq!(
+            Angle::from_degrees_exact_int(300).clamp_rate(previous, max_rate),
+            -max_rate
+        );
+        assert_eq!(degrees(5.0).clamp_rate(previous, max_rate), degrees(5.0));
+    }
+
+    #[test]
+    fn test_step_toward() {
+        let step = Angle::from_degrees_exact_int(30);
+        let zero = Angle::from_repr(0);
+
+        assert_eq!(
+            zero.step_toward(Angle::from_degrees_exact_int(90), step),
+            step
+        );
+        assert_eq!(
+            zero.step_toward(Angle::from_degrees_exact_int(270), step),
+            -step
+        );
+        assert_eq!(
+            Angle::from_degrees_exact_int(10).step_toward(degrees(350.0), step),
+            degrees(350.0)
+        );
+    }
+
+    #[test]
+    fn test_rotate_toward_vector() {
+        let step = Angle::from_degrees_exact_int(30);
+        let zero = Angle::from_repr(0);
+
+        assert_eq!(zero.rotate_toward_vector([0.0, 1.0], step), step);
+        assert_eq!(zero.rotate_toward_vector([0.0, -1.0], step), -step);
+        assert_eq!(step.rotate_toward_vector([0.0, 0.0], step), step);
+        assert_eq!(step.rotate_toward_vector([f64::NAN, 1.0], step), step);
+    }
+
+    #[test]
+    fn test_cmp_ccw_from() {
+        let reference = Angle::from_degrees_exact_int(180);
+        let mut angles = [170, 190, 0].map(Angle::from_degrees_exact_int);
+
+        angles.sort_by(|a, b| a.cmp_ccw_from(*b, reference));
+        assert_eq!(angles, [190, 0, 170].map(Angle::from_degrees_exact_int));
+
+        assert_eq!(
+            reference.cmp_ccw_from(angles[0], reference),
+            core::cmp::Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_wraps_between() {
+        let a = Angle::from_degrees_exact_int(350);
+        let b = Angle::from_degrees_exact_int(10);
+
+        assert_eq!(Angle::wraps_between(a, b, Direction::Ccw), 1);
+        assert_eq!(Angle::wraps_between(b, a, Direction::Ccw), 0);
+        assert_eq!(Angle::wraps_between(b, a, Direction::Cw), -1);
+        assert_eq!(Angle::wraps_between(a, b, Direction::Cw), 0);
+        assert_eq!(Angle::wraps_between(a, a, Direction::Ccw), 0);
+    }
+
+    #[test]
+    fn test_turns_between() {
+        let a = degrees(10.0);
+        let b = degrees(350.0);
+
+        assert!((a.turns_between(b, Direction::Ccw) - 340.0 / 360.0).abs() < 1e-12);
+        assert!((a.turns_between(b, Direction::Cw) - 20.0 / 360.0).abs() < 1e-12);
+        assert!((b.turns_between(a, Direction::Ccw) - 20.0 / 360.0).abs() < 1e-12);
+        assert_eq!(a.turns_between(a, Direction::Cw), 0.0);
+    }
+
+    #[test]
+    fn test_normalize_to() {
+        let error = degrees(350.0).normalize_to(degrees(10.0));
+        assert!((error.as_degrees() + 20.0).abs() < 1e-9);
+
+        let error = degrees(10.0).normalize_to(degrees(350.0));
+        assert!((error.as_degrees() - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_error_degrees() {
+        let error = degrees(350.0).error_degrees(degrees(10.0));
+        assert!((error - 20.0).abs() < 1e-9);
+
+        let error = degrees(10.0).error_degrees(degrees(350.0));
+        assert!((error + 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sign_of_turn() {
+        let from = degrees(0.0);
+
+        assert_eq!(degrees(10.0).sign_of_turn(from), Some(Sign::Positive));
+        assert_eq!(degrees(350.0).sign_of_turn(from), Some(Sign::Negative));
+        assert_eq!(degrees(180.0).sign_of_turn(from), None);
+        assert_eq!(from.sign_of_turn(from), Some(Sign::Positive));
+    }
+
+    #[test]
+    fn test_within_fov() {
+        let half_width = degrees(10.0);
+
+        assert!(degrees(5.0).is_within_fov(degrees(0.0), half_width));
+        assert!(degrees(355.0).is_within_fov(degrees(0.0), half_width));
+        assert!(!degrees(15.0).is_within_fov(degrees(0.0), half_width));
+    }
+
+    #[test]
+    fn test_within_fov_wrapping() {
+        let half_width = degrees(10.0);
+
+        assert!(degrees(5.0).is_within_fov(degrees(355.0), half_width));
+        assert!(degrees(350.0).is_within_fov(degrees(355.0), half_width));
+        assert!(!degrees(10.0).is_within_fov(degrees(355.0), half_width));
+    }
+
+    #[test]
+    fn test_assert_near() {
+        let tolerance = Angle::from_degrees_exact_int(1);
+
+        degrees(359.5).assert_near(degrees(0.25), tolerance);
+        degrees(90.0).assert_near(degrees(90.0), Angle::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "angle 90° is not within 11.25° of 180° (difference: 90°)")]
+    fn test_assert_near_fails() {
+        let tolerance = Angle::from_ratio(1, 32).unwrap();
+
+        Angle::QUARTER_TURN.assert_near(Angle::from_repr(1 << 63), tolerance);
+    }
+}
+
+#[cfg(test)]
+mod ops_tests {
+    use super::Angle;
+
+    #[test]
+    fn test_add_wraps() {
+        let a = Angle::from_repr(0xC0_00_00_00_00_00_00_00);
+        let b = Angle::from_repr(0x80_00_00_00_00_00_00_00);
+
+        assert_eq!(a + b, Angle::from_repr(0x40_00_00_00_00_00_00_00));
+        assert_eq!(b - a, Angle::from_repr(0xC0_00_00_00_00_00_00_00));
+    }
+
+    #[test]
+    fn test_mul_wraps() {
+        let angle = Angle::from_degrees_exact_int(90);
+
+        assert_eq!(angle * 2, Angle::from_degrees_exact_int(180));
+        assert_eq!(angle * 5, angle);
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let quarter = Angle::from_repr(0x40_00_00_00_00_00_00_00);
+        let three_quarters = Angle::from_repr(0xC0_00_00_00_00_00_00_00);
+
+        assert_eq!(quarter.checked_add(quarter), Some(quarter + quarter));
+        assert_eq!(three_quarters.checked_add(quarter), None);
+        assert_eq!(
+            three_quarters.overflowing_add(three_quarters),
+            (Angle::from_repr(0x80_00_00_00_00_00_00_00), true)
+        );
+    }
+
+    #[test]
+    fn test_angular_velocity_step() {
+        let angle = Angle::from_repr(0)
+            .angular_velocity_step(1.0, 0.25)
+            .unwrap();
+        assert_eq!(angle, Angle::from_repr(0x40_00_00_00_00_00_00_00));
+
+        let angle = angle.angular_velocity_step(-2.0, 0.25).unwrap();
+        assert_eq!(angle, Angle::from_repr(0xC0_00_00_00_00_00_00_00));
+
+        assert_eq!(angle.angular_velocity_step(f64::INFINITY, 1.0), None);
+    }
+
+    #[test]
+    fn test_signed_mul_f64() {
+        let quarter = Angle::QUARTER_TURN;
+        let three_quarters = Angle::from_repr(3 << 62);
+
+        assert_eq!(quarter.signed_mul_f64(2.0), Angle::HALF_TURN);
+        assert_eq!(three_quarters.signed_mul_f64(2.0), Angle::HALF_TURN);
+        assert_eq!(
+            three_quarters.signed_mul_f64(0.5),
+            Angle::from_repr(7 << 61)
+        );
+        assert_eq!(quarter.signed_mul_f64(-1.5), Angle::from_repr(5 << 61));
+        assert_eq!(quarter.signed_mul_f64(f64::NAN), Angle::ZERO);
+    }
+
+    #[test]
+    fn test_time_to_align() {
+        let body = Angle::QUARTER_TURN;
+        let target = Angle::ZERO;
+
+        assert_eq!(body.time_to_align(target, 1.0), Some(0.75));
+        assert_eq!(body.time_to_align(target, -1.0), Some(0.25));
+        assert_eq!(body.time_to_align(target, 0.5), Some(1.5));
+        assert_eq!(body.time_to_align(body, 0.0), Some(0.0));
+        assert_eq!(body.time_to_align(target, 0.0), None);
+        assert_eq!(body.time_to_align(target, f64::NAN), None);
+    }
+
+    #[test]
+    fn test_altitude_zenith() {
+        let zenith = Angle::from_degrees_exact_int(30);
+        let altitude = zenith.altitude_from_zenith();
+
+        assert!(altitude.abs_diff(Angle::from_degrees_exact_int(60)).repr() <= 1);
+        assert_eq!(altitude.zenith_from_altitude(), zenith);
+
+        let below = Angle::from_degrees_exact_int(120).altitude_from_zenith();
+        assert!(below.abs_diff(Angle::from_degrees_exact_int(-30)).repr() <= 1);
+
+        assert_eq!(
+            Angle::QUARTER_TURN.altitude_from_zenith(),
+            Angle::from_repr(0)
+        );
+    }
+
+    #[test]
+    fn test_reflections() {
+        let angle = Angle::from_degrees_exact_int(30);
+
+        let check = |reflected: Angle, degrees| {
+            let expected = Angle::from_degrees_exact_int(degrees);
+            assert!(reflected.abs_diff(expected).repr() <= 1);
+        };
+
+        check(angle.reflect_x(), 330);
+        check(angle.reflect_y(), 150);
+        check(angle.point_reflect(), 210);
+
+        assert_eq!(angle.reflect_x(), -angle);
+        assert_eq!(angle.reflect_y().reflect_y(), angle);
+        assert_eq!(angle.reflect_x().reflect_y(), angle.point_reflect());
+    }
+
+    #[test]
+    fn test_conjugate() {
+        let angle = Angle::from_degrees_exact_int(30);
+
+        let expected = Angle::from_degrees_exact_int(330);
+
+        // 30 degrees isn't exactly representable, so the truncation in each
+        // may differ by one unit
+        assert!(angle.conjugate().abs_diff(expected).repr() <= 1);
+        assert_eq!(angle.conjugate(), -angle);
+        assert_eq!(angle.conjugate().conjugate(), angle);
+    }
+}
+
+#[cfg(test)]
+mod interpolation_tests {
+    use super::Angle;
+
+    fn degrees(degrees: f64) -> Angle {
+        Angle::from_degrees(degrees).unwrap()
+    }
+
+    #[test]
+    fn test_lerp_endpoints() {
+        let a = degrees(10.0);
+        let b = degrees(350.0);
+
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+    }
+
+    #[test]
+    fn test_lerp_shorter_arc() {
+        let midpoint = degrees(10.0).lerp(degrees(350.0), 0.5);
+        assert!(midpoint.abs_diff(Angle::from_repr(0)).as_degrees() < 1e-9);
+
+        let quarter = degrees(350.0).lerp(degrees(10.0), 0.25);
+        assert!((quarter.as_degrees() - 355.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lerp_exact() {
+        let a = Angle::from_repr(0x10_00_00_00_00_00_00_00);
+        let b = Angle::from_repr(0xF0_00_00_00_00_00_00_00);
+
+        assert_eq!(a.lerp(b, 0.25), Angle::from_repr(0x08_00_00_00_00_00_00_00));
+        assert_eq!(b.lerp(a, 0.25), Angle::from_repr(0xF8_00_00_00_00_00_00_00));
+    }
+
+    #[test]
+    fn test_lerp_extrapolate() {
+        let a = Angle::from_repr(0x10_00_00_00_00_00_00_00);
+        let b = Angle::from_repr(0x20_00_00_00_00_00_00_00);
+
+        assert_eq!(a.lerp(b, 2.0), Angle::from_repr(0x30_00_00_00_00_00_00_00));
+        assert_eq!(a.lerp(b, -1.0), Angle::from_repr(0x00_00_00_00_00_00_00_00));
+    }
+
+    #[test]
+    fn test_lerp_extrapolate_past_a_turn() {
+        let a = Angle::ZERO;
+        let b = Angle::QUARTER_TURN;
+
+        // 450° and -270° both wrap around to 90°
+        assert_eq!(a.lerp(b, 5.0), Angle::QUARTER_TURN);
+        assert_eq!(a.lerp(b, -3.0), Angle::QUARTER_TURN);
+        assert_eq!(a.lerp(b, 2.5), Angle::from_repr(5 << 61));
+        assert_eq!(b.lerp(a, 5.0), Angle::ZERO);
+
+        // Clockwise arcs extrapolate clockwise
+        let c = Angle::from_repr(0xC0_00_00_00_00_00_00_00);
+        assert_eq!(a.lerp(c, 3.0), Angle::from_repr(1 << 62));
+        assert_eq!(a.lerp(c, -6.0), Angle::HALF_TURN);
+    }
+
+    #[test]
+    fn test_bilinear_angle_constant() {
+        let corners = [Angle::from_repr(0); 4];
+
+        for (u, v) in [(0.0, 0.0), (0.3, 0.7), (1.0, 1.0)] {
+            assert_eq!(Angle::bilinear_angle(corners, u, v), Angle::from_repr(0));
+        }
+    }
+
+    #[test]
+    fn test_bilinear_angle_wraps() {
+        let [low, high] = [350, 10].map(Angle::from_degrees_exact_int);
+        let corners = [low, high, low, high];
+
+        let angle = Angle::bilinear_angle(corners, 0.5, 0.5);
+        assert!(angle.abs_diff(Angle::from_repr(0)).as_degrees() < 1e-9);
+
+        let angle = Angle::bilinear_angle(corners, 0.25, 0.9);
+        assert!(
+            angle
+                .abs_diff(Angle::from_degrees_exact_int(355))
+                .as_degrees()
+                < 1e-9
+        );
+
+        assert_eq!(Angle::bilinear_angle(corners, 0.0, 0.0), low);
+    }
+
+    #[test]
+    fn test_ease_in_out_sine() {
+        assert_eq!(Angle::from_repr(0).ease_in_out_sine(), 0.0);
+        assert_eq!(Angle::from_degrees_exact_int(90).ease_in_out_sine(), 0.5);
+        assert_eq!(Angle::from_degrees_exact_int(180).ease_in_out_sine(), 1.0);
+
+        let ease = Angle::from_degrees_exact_int(60).ease_in_out_sine();
+        assert!((ease - 0.25).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_catmull_rom_endpoints() {
+        let [p0, p1, p2, p3] = [10, 40, 100, 200].map(Angle::from_degrees_exact_int);
+
+        assert_eq!(Angle::catmull_rom(p0, p1, p2, p3, 0.0), p1);
+        assert_eq!(Angle::catmull_rom(p0, p1, p2, p3, 1.0), p2);
+    }
+
+    #[test]
+    fn test_catmull_rom_linear() {
+        // Evenly spaced control points lie on a line, so the spline is linear
+        let [p0, p1, p2, p3] = [330, 350, 10, 30].map(Angle::from_degrees_exact_int);
+
+        for i in 0..=10 {
+            let t = f64::from(i) / 10.0;
+            let angle = Angle::catmull_rom(p0, p1, p2, p3, t);
+            let expected = p1.lerp(p2, t);
+
+            assert!(angle.abs_diff(expected).as_degrees() < 1e-9, "t = {t}");
+        }
+    }
+
+    #[test]
+    fn test_catmull_rom_wraps_smoothly() {
+        let [p0, p1, p2, p3] = [320, 340, 20, 30].map(Angle::from_degrees_exact_int);
+
+        let mut previous = p1;
+        for i in 1..=20 {
+            let t = f64::from(i) / 20.0;
+            let angle = Angle::catmull_rom(p0, p1, p2, p3, t);
+
+            // Each step is a small counterclockwise step; there's no jump
+            // the long way around the circle
+            let step = previous.signed_distance(angle).as_degrees();
+            assert!((0.0..5.0).contains(&step), "step of {step}° at t = {t}");
+
+            previous = angle;
+        }
+    }
+
+    #[test]
+    fn test_blend_shortest_pair_no_pop() {
+        let a = Angle::ZERO;
+        let mut prev = None;
+
+        // As b sweeps past a half turn, the shorter arc flips to the other
+        // side, but the blend should stay continuous
+        for degrees in 170..190 {
+            let b = Angle::from_degrees_exact_int(degrees);
+            let blend = Angle::blend_shortest_pair(a, b, 0.5, prev);
+
+            assert!(
+                (blend.as_degrees() - f64::from(degrees as i32) / 2.0).abs() < 1e-6,
+                "{degrees}°: {blend:?}"
+            );
+            prev = Some(blend);
+        }
+
+        // Without continuity, the shorter arc pops across
+        let b = Angle::from_degrees_exact_int(189);
+        assert!(Angle::blend_shortest_pair(a, b, 0.5, None).as_degrees() > 180.0);
+    }
+
+    #[test]
+    fn test_blend_shortest_pair_endpoints() {
+        let a = Angle::from_degrees_exact_int(10);
+        let b = Angle::from_degrees_exact_int(200);
+        let prev = Some(Angle::from_degrees_exact_int(100));
+
+        assert_eq!(Angle::blend_shortest_pair(a, b, 0.0, prev), a);
+        assert_eq!(Angle::blend_shortest_pair(a, b, 0.5, None), a.lerp(b, 0.5));
+    }
+
+    #[test]
+    fn test_sample_keyframes_wraps() {
+        let keys = [(1.0, degrees(350.0)), (3.0, degrees(30.0))];
+
+        let mid = Angle::sample_keyframes(&keys, 2.0).unwrap();
+        assert!((mid.as_degrees() - 10.0).abs() < 1e-9);
+
+        let quarter = Angle::sample_keyframes(&keys, 1.5).unwrap();
+        assert!(quarter.abs_diff(Angle::ZERO).as_degrees() < 1e-9);
+    }
+
+    #[test]
+    fn test_sample_keyframes_clamps() {
+        let keys = [
+            (0.0, degrees(0.0)),
+            (1.0, degrees(90.0)),
+            (2.0, degrees(45.0)),
+        ];
+
+        assert_eq!(Angle::sample_keyframes(&keys, -1.0), Some(degrees(0.0)));
+        assert_eq!(Angle::sample_keyframes(&keys, 1.0), Some(degrees(90.0)));
+        assert_eq!(Angle::sample_keyframes(&keys, 5.0), Some(degrees(45.0)));
+        assert_eq!(Angle::sample_keyframes(&[], 0.0), None);
+
+        let later = Angle::sample_keyframes(&keys, 1.5).unwrap();
+        assert!((later.as_degrees() - 67.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_remap_range() {
+        let (in_lo, in_hi) = (Angle::ZERO, Angle::HALF_TURN);
+        let (out_lo, out_hi) = (Angle::QUARTER_TURN, Angle::from_repr(3 << 62));
+
+        let remap = |angle: Angle| angle.remap_range(in_lo, in_hi, out_lo, out_hi);
+
+        assert_eq!(remap(Angle::QUARTER_TURN), Angle::HALF_TURN);
+        assert_eq!(remap(in_lo), out_lo);
+        assert_eq!(remap(in_hi), out_hi);
+
+        // Angles past either end clamp to the nearer one
+        assert_eq!(remap(degrees(200.0)), out_hi);
+        assert_eq!(remap(degrees(300.0)), out_lo);
+    }
+
+    #[test]
+    fn test_remap_range_across_zero() {
+        // A 270° gauge sweep, from 225° counterclockwise through zero to 135°,
+        // mapped onto the first quarter turn
+        let (in_lo, in_hi) = (degrees(225.0), degrees(135.0));
+        let (out_lo, out_hi) = (Angle::ZERO, Angle::QUARTER_TURN);
+
+        let mid = Angle::ZERO.remap_range(in_lo, in_hi, out_lo, out_hi);
+        assert!((mid.as_degrees() - 45.0).abs() < 1e-9);
+
+        assert_eq!(in_lo.remap_range(in_lo, in_lo, out_lo, out_hi), out_lo);
+    }
+
+    #[test]
+    fn test_slerp_vector_midpoint() {
+        let [x, y] = degrees(0.0).slerp_vector(degrees(90.0), 0.5);
+
+        assert!((x - core::f64::consts::FRAC_1_SQRT_2).abs() < 1e-4);
+        assert!((y - core::f64::consts::FRAC_1_SQRT_2).abs() < 1e-4);
+    }
+}
+
+#[cfg(test)]
+mod vector_tests {
+    use super::Angle;
+
+    fn assert_near([x, y]: [f64; 2], [ex, ey]: [f64; 2]) {
+        assert!(
+            (x - ex).abs() < 1e-12 && (y - ey).abs() < 1e-12,
+            "[{x}, {y}] is not near [{ex}, {ey}]"
+        );
+    }
+
+    #[test]
+    fn test_rotate_vector() {
+        let quarter = Angle::from_degrees_exact_int(90);
+        assert_near(quarter.rotate_vector([1.0, 0.0]), [0.0, 1.0]);
+        assert_near(quarter.rotate_vector([0.0, 2.0]), [-2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_rotate_point_around_origin() {
+        let quarter = Angle::from_degrees_exact_int(90);
+        assert_near(
+            quarter.rotate_point_around([1.0, 0.0], [0.0, 0.0]),
+            [0.0, 1.0],
+        );
+    }
+
+    #[test]
+    fn test_rotate_point_around_itself() {
+        let quarter = Angle::from_degrees_exact_int(90);
+        assert_eq!(
+            quarter.rotate_point_around([1.0, 0.0], [1.0, 0.0]),
+            [1.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_rotate_point_around_pivot() {
+        let half = Angle::from_degrees_exact_int(180);
+        assert_near(
+            half.rotate_point_around([3.0, 1.0], [1.0, 1.0]),
+            [-1.0, 1.0],
+        );
     }
 }
@@ -0,0 +1,117 @@
+//! Bulk operations over slices of angles.
+
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+use crate::Angle;
+
+/// Compute the sine and cosine of every angle in `angles`, writing them to
+/// the corresponding elements of `sin_out` and `cos_out`.
+///
+/// # Panics
+///
+/// Panics if the three slices don't all have the same length.
+pub fn sin_cos_slice(angles: &[Angle], sin_out: &mut [f64], cos_out: &mut [f64]) {
+    assert_eq!(angles.len(), sin_out.len(), "sin_out has the wrong length");
+    assert_eq!(angles.len(), cos_out.len(), "cos_out has the wrong length");
+
+    angles
+        .iter()
+        .zip(sin_out)
+        .zip(cos_out)
+        .for_each(|((angle, sin), cos)| (*sin, *cos) = angle.sin_cos());
+}
+
+/// Convert every value in `input` from degrees to an angle, writing them to
+/// the corresponding elements of `out`. If any value isn't finite, this stops
+/// and returns `Err` with its index; the elements of `out` before that index
+/// will already have been written.
+///
+/// # Panics
+///
+/// Panics if the two slices don't have the same length.
+pub fn from_degrees_batch(input: &[f64], out: &mut [Angle]) -> Result<(), usize> {
+    assert_eq!(input.len(), out.len(), "out has the wrong length");
+
+    input
+        .iter()
+        .zip(out)
+        .enumerate()
+        .try_for_each(|(i, (&degrees, angle))| {
+            *angle = Angle::from_degrees(degrees).ok_or(i)?;
+            Ok(())
+        })
+}
+
+/// Rotate every complex sample in `data` by `angle`, by multiplying it by the
+/// phasor `e^(i * angle)`. The phasor is computed only once, so this is much
+/// cheaper than rotating each sample separately.
+#[cfg(feature = "num-complex")]
+pub fn rotate_complex_slice(angle: Angle, data: &mut [Complex<f64>]) {
+    let phasor = angle.to_complex();
+    data.iter_mut().for_each(|sample| *sample *= phasor);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_degrees_batch, sin_cos_slice};
+    use crate::Angle;
+
+    #[test]
+    fn test_sin_cos_slice() {
+        let start = Angle::from_repr(12345);
+        let step = Angle::from_ratio(1, 1000).unwrap();
+        let angles: [Angle; 1000] = core::array::from_fn(|i| start + step * i as u64);
+
+        let mut sin = [0.0; 1000];
+        let mut cos = [0.0; 1000];
+
+        sin_cos_slice(&angles, &mut sin, &mut cos);
+
+        for (i, angle) in angles.iter().enumerate() {
+            assert_eq!((sin[i], cos[i]), angle.sin_cos());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cos_out has the wrong length")]
+    fn test_sin_cos_slice_mismatch() {
+        sin_cos_slice(&[Angle::from_repr(0)], &mut [0.0], &mut []);
+    }
+
+    #[test]
+    fn test_from_degrees_batch() {
+        let input = [0.0, 90.0, -90.0, 720.0, 45.5];
+        let mut out = [Angle::ZERO; 5];
+
+        assert_eq!(from_degrees_batch(&input, &mut out), Ok(()));
+
+        for (degrees, angle) in input.iter().zip(out) {
+            assert_eq!(Some(angle), Angle::from_degrees(*degrees));
+        }
+    }
+
+    #[test]
+    fn test_from_degrees_batch_nan() {
+        let input = [10.0, 20.0, 30.0, f64::NAN, 50.0];
+        let mut out = [Angle::ZERO; 5];
+
+        assert_eq!(from_degrees_batch(&input, &mut out), Err(3));
+        assert_eq!(Some(out[2]), Angle::from_degrees(30.0));
+        assert_eq!(out[4], Angle::ZERO);
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn test_rotate_complex_slice() {
+        use num_complex::Complex;
+
+        use super::rotate_complex_slice;
+
+        let mut data = [Complex::new(1.0, 0.0), Complex::new(0.0, 2.0)];
+        rotate_complex_slice(Angle::from_degrees_exact_int(90), &mut data);
+
+        assert!((data[0] - Complex::new(0.0, 1.0)).l1_norm() < 1e-12);
+        assert!((data[1] - Complex::new(-2.0, 0.0)).l1_norm() < 1e-12);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::Angle;
+
+/// One of the eight principal points of the compass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardinalDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+use CardinalDirection::*;
+
+impl CardinalDirection {
+    /// All eight directions, in clockwise order starting from North.
+    pub const ALL: [CardinalDirection; 8] = [
+        North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
+    ];
+
+    /// Get the compass bearing of this direction, measured clockwise from
+    /// North; for instance, `East` is 90 degrees. This is exact. Note that
+    /// the result is a bearing, not an angle in the mathematical convention
+    /// used by [`sin`][Angle::sin], [`atan2`][Angle::atan2], and so on
+    /// (counterclockwise from East); use
+    /// [`from_compass_bearing`][Angle::from_compass_bearing] to get one of
+    /// those.
+    #[inline]
+    #[must_use]
+    pub const fn to_angle(self) -> Angle {
+        Angle::from_repr((self as u64) << 61)
+    }
+}
+
+impl Angle {
+    /// Interpreting this angle as a compass bearing (measured clockwise from
+    /// North), find the nearest of the eight principal compass directions.
+    /// Bearings exactly halfway between two directions round clockwise. This
+    /// takes a bearing, not an angle in the mathematical convention used by
+    /// [`sin`][Angle::sin], [`atan2`][Angle::atan2], and so on
+    /// (counterclockwise from East); convert those with
+    /// [`as_compass_bearing`][Angle::as_compass_bearing] first, or with
+    /// `Angle::QUARTER_TURN - angle`.
+    #[inline]
+    #[must_use]
+    pub const fn nearest_direction(self) -> CardinalDirection {
+        let index = ((self.repr() >> 60) + 1) >> 1;
+        CardinalDirection::ALL[(index % 8) as usize]
+    }
+
+    /// Interpreting this angle in the usual mathematical convention (measured
+    /// counterclockwise from East), get the equivalent compass bearing in
+    /// degrees, measured clockwise from North and in the range `[0, 360)`.
+    /// The two conventions are easy to conflate: North is 90°
+    /// mathematically but a bearing of 0°, while East is 0° mathematically
+    /// but a bearing of 90°. The flip is exact: it's `QUARTER_TURN - self`.
+    #[inline]
+    #[must_use]
+    pub fn as_compass_bearing(self) -> f64 {
+        (Angle::QUARTER_TURN - self).as_degrees()
+    }
+
+    /// Create a new angle, in the usual mathematical convention (measured
+    /// counterclockwise from East), from a compass bearing in degrees,
+    /// measured clockwise from North. This is the inverse of
+    /// [`as_compass_bearing`][Angle::as_compass_bearing]. Returns `None` if
+    /// the bearing isn't finite.
+    #[inline]
+    #[must_use]
+    pub fn from_compass_bearing(bearing: f64) -> Option<Angle> {
+        Some(Angle::QUARTER_TURN - Angle::from_degrees(bearing)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CardinalDirection;
+    use crate::Angle;
+
+    #[test]
+    fn test_nearest_direction() {
+        let cases = [
+            (0, CardinalDirection::North),
+            (80, CardinalDirection::East),
+            (100, CardinalDirection::East),
+            (140, CardinalDirection::SouthEast),
+            (200, CardinalDirection::South),
+            (350, CardinalDirection::North),
+        ];
+
+        for (degrees, expected) in cases {
+            let angle = Angle::from_degrees_exact_int(degrees);
+            assert_eq!(angle.nearest_direction(), expected, "{degrees}°");
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for direction in CardinalDirection::ALL {
+            assert_eq!(direction.to_angle().nearest_direction(), direction);
+        }
+
+        assert_eq!(
+            CardinalDirection::East.to_angle(),
+            Angle::from_degrees_exact_int(90)
+        );
+    }
+
+    #[test]
+    fn test_compass_bearing() {
+        let east = Angle::ZERO;
+        let north = Angle::QUARTER_TURN;
+
+        assert_eq!(east.as_compass_bearing(), 90.0);
+        assert_eq!(north.as_compass_bearing(), 0.0);
+        assert_eq!(Angle::HALF_TURN.as_compass_bearing(), 270.0);
+
+        assert_eq!(Angle::from_compass_bearing(90.0), Some(east));
+        assert_eq!(Angle::from_compass_bearing(0.0), Some(north));
+        assert_eq!(Angle::from_compass_bearing(-90.0), Some(Angle::HALF_TURN));
+        assert_eq!(Angle::from_compass_bearing(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_compass_bearing_of_vector() {
+        // A vector pointing due north, in the mathematical convention
+        let north = Angle::atan2(1.0, 0.0).unwrap();
+        assert!(north.as_compass_bearing().abs() < 1e-9);
+
+        // Converted to a bearing, it's nearest to North; taken directly as a
+        // bearing, it would be East
+        let bearing = Angle::QUARTER_TURN - north;
+        assert_eq!(bearing.nearest_direction(), CardinalDirection::North);
+    }
+
+    #[test]
+    fn test_compass_bearing_matches_directions() {
+        for direction in CardinalDirection::ALL {
+            let bearing = direction.to_angle();
+            let angle = Angle::from_compass_bearing(bearing.as_degrees()).unwrap();
+
+            assert_eq!(angle.as_compass_bearing(), bearing.as_degrees());
+            assert_eq!((Angle::QUARTER_TURN - angle).nearest_direction(), direction);
+        }
+    }
+}
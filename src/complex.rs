@@ -0,0 +1,35 @@
+//! Interop with [`num_complex`], treating angles as the phase of a phasor.
+
+use num_complex::Complex;
+
+use crate::Angle;
+
+impl Angle {
+    /// Get the unit phasor `e^(i * self)`, which has this angle as its phase.
+    #[inline]
+    #[must_use]
+    pub fn to_complex(self) -> Complex<f64> {
+        let (sin, cos) = self.sin_cos();
+        Complex::new(cos, sin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Angle;
+
+    #[test]
+    fn test_to_complex() {
+        let value = Angle::from_degrees_exact_int(90).to_complex();
+
+        assert!(value.re.abs() < 1e-12);
+        assert_eq!(value.im, 1.0);
+    }
+
+    #[test]
+    fn test_conjugate_composes() {
+        let angle = Angle::from_degrees_exact_int(30);
+
+        assert_eq!(angle.conjugate().to_complex(), angle.to_complex().conj());
+    }
+}
@@ -6,3 +6,13 @@ pub const TAU: BaseRepr<3> = BaseRepr::new(0xC90F_DAA2_2168_C234);
 
 /// 360 in `BaseRepr` form
 pub const DEGREES: BaseRepr<9> = BaseRepr::new(0xB400_0000_0000_0000);
+
+/// The value of 1 / TAU, to 256 bits of precision, as big-endian limbs of a
+/// binary fraction (so the first limb is the first 64 bits after the point).
+/// This is enough precision to reduce any float up to 2^181 radians.
+pub const INV_TAU: [u64; 4] = [
+    0x28BE_60DB_9391_054A,
+    0x7F09_D5F4_7D4D_3770,
+    0x36D8_A566_4F10_E410,
+    0x7F94_58EA_F7AE_F158,
+];
@@ -0,0 +1,173 @@
+//! A table-free alternative to the sine table, using CORDIC rotations.
+//!
+//! CORDIC evaluates sine and cosine by rotating a vector through a sequence
+//! of fixed angles `atan(2^-i)`, each of which can be applied with only
+//! shifts and additions. Each iteration adds about one bit of accuracy: after
+//! `n` iterations, the angular error is at most `atan(2^(1-n))`, so the
+//! [`ITERATIONS`] used here leave an error of around `2^-58` radians, which is
+//! dominated by the rounding of the final conversion to `f64`. This makes it
+//! considerably more accurate than the sine table, at the cost of speed.
+
+use crate::Angle;
+
+/// The number of CORDIC iterations performed.
+pub const ITERATIONS: usize = 60;
+
+/// `atan(2^-i)` for each iteration, as a fraction of a full turn in repr
+/// units, rounded to the nearest unit.
+const ATAN_TABLE: [u64; ITERATIONS] = [
+    0x2000_0000_0000_0000,
+    0x12E4_051D_9DF3_0866,
+    0x09FB_385B_5EE3_9E8E,
+    0x0511_11D4_1DDD_9A1B,
+    0x028B_0D43_0E58_9AED,
+    0x0145_D7E1_5904_6278,
+    0x00A2_F61E_5C28_262A,
+    0x0051_7C55_11D4_42AF,
+    0x0028_BE53_46D0_C337,
+    0x0014_5F2E_BB30_AB38,
+    0x000A_2F98_0091_BA7B,
+    0x0005_17CC_14A8_0CB7,
+    0x0002_8BE6_0CDF_EC62,
+    0x0001_45F3_06C1_72F2,
+    0x0000_A2F9_836A_E911,
+    0x0000_517C_C1B6_BA7C,
+    0x0000_28BE_60DB_85FC,
+    0x0000_145F_306D_C816,
+    0x0000_0A2F_9836_E4AE,
+    0x0000_0517_CC1B_726B,
+    0x0000_028B_E60D_B938,
+    0x0000_0145_F306_DC9C,
+    0x0000_00A2_F983_6E4E,
+    0x0000_0051_7CC1_B727,
+    0x0000_0028_BE60_DB94,
+    0x0000_0014_5F30_6DCA,
+    0x0000_000A_2F98_36E5,
+    0x0000_0005_17CC_1B72,
+    0x0000_0002_8BE6_0DB9,
+    0x0000_0001_45F3_06DD,
+    0x0000_0000_A2F9_836E,
+    0x0000_0000_517C_C1B7,
+    0x0000_0000_28BE_60DC,
+    0x0000_0000_145F_306E,
+    0x0000_0000_0A2F_9837,
+    0x0000_0000_0517_CC1B,
+    0x0000_0000_028B_E60E,
+    0x0000_0000_0145_F307,
+    0x0000_0000_00A2_F983,
+    0x0000_0000_0051_7CC2,
+    0x0000_0000_0028_BE61,
+    0x0000_0000_0014_5F30,
+    0x0000_0000_000A_2F98,
+    0x0000_0000_0005_17CC,
+    0x0000_0000_0002_8BE6,
+    0x0000_0000_0001_45F3,
+    0x0000_0000_0000_A2FA,
+    0x0000_0000_0000_517D,
+    0x0000_0000_0000_28BE,
+    0x0000_0000_0000_145F,
+    0x0000_0000_0000_0A30,
+    0x0000_0000_0000_0518,
+    0x0000_0000_0000_028C,
+    0x0000_0000_0000_0146,
+    0x0000_0000_0000_00A3,
+    0x0000_0000_0000_0051,
+    0x0000_0000_0000_0029,
+    0x0000_0000_0000_0014,
+    0x0000_0000_0000_000A,
+    0x0000_0000_0000_0005,
+];
+
+/// The reciprocal of the total CORDIC gain over all of the iterations, in
+/// Q2.62 fixed point. Starting the vector with this length means it ends up
+/// with a length of exactly 1.
+const INV_GAIN: i64 = 0x26DD_3B6A_10D7_969A;
+
+/// The value of 1 in Q2.62 fixed point.
+const ONE: f64 = (1u64 << 62) as f64;
+
+impl Angle {
+    /// Compute the sine and cosine of this angle with CORDIC rotations,
+    /// rather than the sine table. See the [module documentation][self] for
+    /// the accuracy.
+    #[must_use]
+    pub fn cordic_sin_cos(self) -> (f64, f64) {
+        // CORDIC only converges for angles within about 99 degrees of zero,
+        // so rotate to the nearest quadrant first, leaving a residual in the
+        // range [-45, 45) degrees.
+        let rounded = self.repr().wrapping_add(1 << 61);
+        let quadrant = rounded >> 62;
+        let mut residual = (rounded & (u64::MAX >> 2)).wrapping_sub(1 << 61) as i64;
+
+        let mut x = INV_GAIN;
+        let mut y = 0i64;
+
+        for (i, &atan) in ATAN_TABLE.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+
+            if residual >= 0 {
+                (x, y) = (x - dx, y + dy);
+                residual -= atan as i64;
+            } else {
+                (x, y) = (x + dx, y - dy);
+                residual += atan as i64;
+            }
+        }
+
+        let (sin, cos) = (y as f64 / ONE, x as f64 / ONE);
+
+        match quadrant {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            _ => (-cos, sin),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Angle;
+
+    #[test]
+    fn test_matches_table() {
+        for i in 0..4096 {
+            let angle = Angle::from_ratio(i, 4096).unwrap();
+            let (sin, cos) = angle.cordic_sin_cos();
+            let (table_sin, table_cos) = angle.sin_cos();
+
+            // The table itself is only accurate to about 2e-5
+            assert!((sin - table_sin).abs() < 1e-4, "sin of {angle:?}");
+            assert!((cos - table_cos).abs() < 1e-4, "cos of {angle:?}");
+        }
+    }
+
+    #[test]
+    fn test_accuracy() {
+        for i in 0..4096 {
+            let angle = Angle::from_ratio(i * 7919, 4096 * 13).unwrap();
+            let (sin, cos) = angle.cordic_sin_cos();
+            let radians = angle.as_radians();
+
+            assert!((sin - libm::sin(radians)).abs() < 1e-15, "sin of {angle:?}");
+            assert!((cos - libm::cos(radians)).abs() < 1e-15, "cos of {angle:?}");
+        }
+    }
+
+    #[test]
+    fn test_cardinals() {
+        let cases = [
+            (0, 0.0, 1.0),
+            (90, 1.0, 0.0),
+            (180, 0.0, -1.0),
+            (270, -1.0, 0.0),
+        ];
+
+        for (degrees, expected_sin, expected_cos) in cases {
+            let (sin, cos) = Angle::from_degrees_exact_int(degrees).cordic_sin_cos();
+
+            assert!((sin - expected_sin).abs() < 1e-15, "{degrees}°");
+            assert!((cos - expected_cos).abs() < 1e-15, "{degrees}°");
+        }
+    }
+}
@@ -0,0 +1,9 @@
+/// A direction of rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Clockwise; the direction of decreasing angles.
+    Cw,
+
+    /// Counterclockwise; the direction of increasing angles.
+    Ccw,
+}
@@ -0,0 +1,91 @@
+//! Formatting helpers that produce owned strings, for report generation.
+
+use alloc::{format, string::String};
+
+use crate::Angle;
+
+/// The number of arcseconds in a full turn.
+const ARCSECONDS: u128 = 360 * 60 * 60;
+
+impl Angle {
+    /// Format this angle in degrees, minutes, and seconds, rounded to the
+    /// nearest whole arcsecond, such as `30°15'30"`. The rounding is done
+    /// exactly on the repr, so the minutes and seconds never read as 60.
+    #[must_use]
+    pub fn to_dms_string(self) -> String {
+        let repr = self.repr() as u128;
+        let seconds = ((repr * ARCSECONDS + (1 << 63)) >> 64) % ARCSECONDS;
+
+        let degrees = seconds / 3600;
+        let minutes = (seconds / 60) % 60;
+        let seconds = seconds % 60;
+
+        format!("{degrees}°{minutes}'{seconds}\"")
+    }
+
+    /// Format this angle as a decimal number of radians, such as
+    /// `1.5707963267948966 rad`.
+    #[must_use]
+    pub fn to_radians_string(self) -> String {
+        format!("{} rad", self.as_radians())
+    }
+
+    /// Format the top `bits` bits of this angle's repr as a zero-padded binary
+    /// fraction of a turn, such as `1000` for a half turn at 4 bits. This is
+    /// computed directly from the repr (it truncates, like
+    /// [`as_bam_scaled`][Angle::as_bam_scaled]), so it's independent of any
+    /// float formatting, which makes it suitable for golden files. Returns
+    /// `None` if `bits` is greater than 64.
+    #[must_use]
+    pub fn to_fixed_string(self, bits: u32) -> Option<String> {
+        let value = self.as_bam_scaled(bits)?;
+
+        Some(match bits {
+            0 => String::new(),
+            bits => format!("{value:0width$b}", width = bits as usize),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Angle;
+
+    #[test]
+    fn test_dms_string() {
+        let seconds = (30 * 60 + 15) * 60 + 30;
+        let angle = Angle::from_ratio(seconds, 360 * 60 * 60).unwrap();
+
+        assert_eq!(angle.to_dms_string(), "30°15'30\"");
+    }
+
+    #[test]
+    fn test_dms_string_rounding() {
+        let angle = Angle::from_degrees(30.0 + 15.0 / 60.0 + 29.7 / 3600.0).unwrap();
+        assert_eq!(angle.to_dms_string(), "30°15'30\"");
+
+        let angle = Angle::from_degrees(359.99999).unwrap();
+        assert_eq!(angle.to_dms_string(), "0°0'0\"");
+    }
+
+    #[test]
+    fn test_radians_string() {
+        let angle = Angle::from_degrees_exact_int(180);
+        assert_eq!(angle.to_radians_string(), "3.141592653589793 rad");
+    }
+
+    #[test]
+    fn test_fixed_string() {
+        let half = Angle::from_repr(1 << 63);
+        assert_eq!(half.to_fixed_string(4).as_deref(), Some("1000"));
+
+        let angle = Angle::from_degrees_exact_int(90);
+        assert_eq!(angle.to_fixed_string(8).as_deref(), Some("01000000"));
+        assert_eq!(angle.to_fixed_string(1).as_deref(), Some("0"));
+        assert_eq!(angle.to_fixed_string(0).as_deref(), Some(""));
+
+        let max = Angle::from_repr(u64::MAX).to_fixed_string(64).unwrap();
+        assert_eq!(max, "1".repeat(64));
+        assert_eq!(angle.to_fixed_string(65), None);
+    }
+}
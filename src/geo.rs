@@ -0,0 +1,189 @@
+//! Spherical geometry: conversions between angular coordinates and 3D
+//! vectors, and navigation between points on a sphere.
+
+use crate::Angle;
+
+/// Convert horizontal coordinates (an `elevation` above the horizon and an
+/// `azimuth`) to a 3D unit vector `[x, y, z]`. The axes are east-north-up:
+/// `x` points east, `y` points north, and `z` points straight up. The
+/// azimuth is a compass bearing, measured clockwise from north, so an
+/// azimuth of 90° points east. Elevations in the fourth quadrant are below
+/// the horizon.
+#[must_use]
+pub fn elevation_azimuth_to_vector(elevation: Angle, azimuth: Angle) -> [f64; 3] {
+    let (sin_el, cos_el) = elevation.sin_cos();
+    let (sin_az, cos_az) = azimuth.sin_cos();
+
+    [cos_el * sin_az, cos_el * cos_az, sin_el]
+}
+
+/// Find the geographic midpoint of two points, given as latitudes and
+/// longitudes: the point halfway between them along the great circle that
+/// connects them, which generally isn't the average of their coordinates.
+/// This assumes a spherical earth. Southern latitudes and western longitudes
+/// are negative (that is, in the fourth quadrant). Returns the `(latitude,
+/// longitude)` of the midpoint.
+#[must_use]
+pub fn geographic_midpoint(lat1: Angle, lon1: Angle, lat2: Angle, lon2: Angle) -> (Angle, Angle) {
+    let (sin_lat1, cos_lat1) = lat1.sin_cos();
+    let (sin_lat2, cos_lat2) = lat2.sin_cos();
+    let (sin_dlon, cos_dlon) = (lon2 - lon1).sin_cos();
+
+    let bx = cos_lat2 * cos_dlon;
+    let by = cos_lat2 * sin_dlon;
+
+    // All of these values are finite, so atan2 always succeeds
+    let lat = Angle::atan2(sin_lat1 + sin_lat2, libm::hypot(cos_lat1 + bx, by));
+    let dlon = Angle::atan2(by, cos_lat1 + bx);
+
+    (lat.unwrap_or_default(), lon1 + dlon.unwrap_or_default())
+}
+
+/// Find the destination reached by travelling from the point at `lat` and
+/// `lon` along a great circle, with an initial compass `bearing` (measured
+/// clockwise from north) for an angular `distance` (the central angle between
+/// the start and the destination). This assumes a spherical earth; to use a
+/// distance in meters, divide it by the radius of the earth to get radians.
+/// As with [`geographic_midpoint`], southern latitudes and western longitudes
+/// are negative. Returns the `(latitude, longitude)` of the destination.
+#[must_use]
+pub fn geodesic_destination(
+    lat: Angle,
+    lon: Angle,
+    bearing: Angle,
+    distance: Angle,
+) -> (Angle, Angle) {
+    let (sin_lat, cos_lat) = lat.sin_cos();
+    let (sin_bearing, cos_bearing) = bearing.sin_cos();
+    let (sin_distance, cos_distance) = distance.sin_cos();
+
+    // Rounding in the table can push this just past the poles, so clamp it
+    let sin_dest = (sin_lat * cos_distance + cos_lat * sin_distance * cos_bearing).clamp(-1.0, 1.0);
+
+    let dest_lat = Angle::asin(sin_dest);
+    let dlon = Angle::atan2(
+        sin_bearing * sin_distance * cos_lat,
+        cos_distance - sin_lat * sin_dest,
+    );
+
+    (dest_lat.unwrap_or_default(), lon + dlon.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{elevation_azimuth_to_vector, geodesic_destination, geographic_midpoint};
+    use crate::Angle;
+
+    fn assert_near([x, y, z]: [f64; 3], [ex, ey, ez]: [f64; 3]) {
+        assert!(
+            (x - ex).abs() < 1e-4 && (y - ey).abs() < 1e-4 && (z - ez).abs() < 1e-4,
+            "{:?} is not near {:?}",
+            [x, y, z],
+            [ex, ey, ez]
+        );
+    }
+
+    #[test]
+    fn test_zenith() {
+        let up = Angle::from_degrees_exact_int(90);
+
+        for azimuth in [0, 45, 200] {
+            let azimuth = Angle::from_degrees_exact_int(azimuth);
+            assert_near(elevation_azimuth_to_vector(up, azimuth), [0.0, 0.0, 1.0]);
+        }
+    }
+
+    #[test]
+    fn test_horizon() {
+        let horizon = Angle::from_repr(0);
+        let vector =
+            |azimuth| elevation_azimuth_to_vector(horizon, Angle::from_degrees_exact_int(azimuth));
+
+        assert_near(vector(0), [0.0, 1.0, 0.0]);
+        assert_near(vector(90), [1.0, 0.0, 0.0]);
+        assert_near(vector(180), [0.0, -1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_unit_length() {
+        let elevation = Angle::from_degrees_exact_int(30);
+        let azimuth = Angle::from_degrees_exact_int(120);
+        let [x, y, z] = elevation_azimuth_to_vector(elevation, azimuth);
+
+        assert!((x * x + y * y + z * z - 1.0).abs() < 1e-4);
+        assert!((z - 0.5).abs() < 1e-4);
+    }
+
+    fn degrees(degrees: f64) -> Angle {
+        Angle::from_degrees(degrees).unwrap()
+    }
+
+    fn assert_angle_near(angle: Angle, expected: f64) {
+        angle.assert_near(degrees(expected), degrees(1e-3));
+    }
+
+    #[test]
+    fn test_midpoint_equator() {
+        let zero = Angle::ZERO;
+        let (lat, lon) = geographic_midpoint(zero, degrees(10.0), zero, degrees(50.0));
+
+        assert_angle_near(lat, 0.0);
+        assert_angle_near(lon, 30.0);
+
+        let (lat, lon) = geographic_midpoint(zero, degrees(170.0), zero, degrees(-170.0));
+        assert_angle_near(lat, 0.0);
+        assert_angle_near(lon, 180.0);
+    }
+
+    #[test]
+    fn test_midpoint_meridian() {
+        let lon = degrees(-20.0);
+        let (lat, mid_lon) = geographic_midpoint(degrees(-10.0), lon, degrees(40.0), lon);
+
+        assert_angle_near(lat, 15.0);
+        assert_angle_near(mid_lon, -20.0);
+    }
+
+    #[test]
+    fn test_midpoint_great_circle() {
+        // The great circle between two points at the same northern latitude
+        // bulges toward the pole
+        let lat = degrees(45.0);
+        let (mid_lat, mid_lon) = geographic_midpoint(lat, degrees(0.0), lat, degrees(90.0));
+
+        assert!(mid_lat.as_degrees() > 45.0);
+        assert_angle_near(mid_lat, libm::atan(core::f64::consts::SQRT_2).to_degrees());
+        assert_angle_near(mid_lon, 45.0);
+    }
+
+    #[test]
+    fn test_destination_north_to_pole() {
+        let (lat, _) =
+            geodesic_destination(Angle::ZERO, degrees(25.0), Angle::ZERO, Angle::QUARTER_TURN);
+
+        assert_angle_near(lat, 90.0);
+    }
+
+    #[test]
+    fn test_destination_along_equator() {
+        let (lat, lon) =
+            geodesic_destination(Angle::ZERO, degrees(-10.0), degrees(90.0), degrees(30.0));
+
+        assert_angle_near(lat, 0.0);
+        assert_angle_near(lon, 20.0);
+    }
+
+    #[test]
+    fn test_destination_round_trip() {
+        let (lat, lon) = (degrees(40.0), degrees(-75.0));
+        let (dest_lat, dest_lon) = geodesic_destination(lat, lon, degrees(180.0), degrees(50.0));
+
+        assert_angle_near(dest_lat, -10.0);
+        assert_angle_near(dest_lon, -75.0);
+
+        let (back_lat, back_lon) =
+            geodesic_destination(dest_lat, dest_lon, Angle::ZERO, degrees(50.0));
+        assert_angle_near(back_lat, 40.0);
+        assert_angle_near(back_lon, -75.0);
+    }
+}
@@ -1,12 +1,37 @@
 #![no_std]
-#![feature(const_float_classify)]
-#![feature(const_float_bits_conv)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 mod angle;
+pub mod batch;
+mod compass;
+#[cfg(feature = "num-complex")]
+mod complex;
 mod consts;
+#[cfg(feature = "cordic")]
+pub mod cordic;
+mod direction;
+#[cfg(feature = "alloc")]
+mod format;
+pub mod geo;
+mod optics;
 mod repr;
+mod round;
+mod sign;
+mod signed;
+mod stats;
 mod table;
 mod trig;
-mod sign;
+mod waveform;
+mod wrap;
 
-pub use angle::Angle;
+pub use angle::{Angle, BisectorSide};
+pub use compass::CardinalDirection;
+pub use direction::Direction;
+pub use round::RoundMode;
+pub use sign::Sign;
+pub use signed::SignedAngle;
+pub use table::{sine_table, sine_table_len, verify_sine_table};
+pub use waveform::PhaseAccumulator;
+pub use wrap::{wrap_degrees, wrap_degrees_signed, wrap_radians, wrap_radians_signed};
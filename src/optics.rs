@@ -0,0 +1,99 @@
+//! Geometric optics, treating an angle as the angle between a ray and the
+//! normal of a surface.
+
+use crate::Angle;
+
+impl Angle {
+    /// Refract a ray passing from a medium with refractive index `n1` into
+    /// one with index `n2`, using Snell's law. `self` is the angle of
+    /// incidence, measured from the surface normal, and the result is the
+    /// angle of refraction, `asin(n1 / n2 * sin(self))`. Returns `None` in the
+    /// case of total internal reflection, where there is no refracted ray.
+    #[inline]
+    #[must_use]
+    pub fn refract(self, n1: f64, n2: f64) -> Option<Self> {
+        Self::asin(n1 / n2 * self.sin())
+    }
+
+    /// Compute the fraction of light reflected by a surface between a medium
+    /// with refractive index `n1` and one with index `n2`, for unpolarized
+    /// light arriving at this angle of incidence (measured from the surface
+    /// normal). This is the average of the Fresnel equations for the `s` and
+    /// `p` polarizations, using [`refract`][Angle::refract] for the angle of
+    /// transmission. In the case of total internal reflection, all of the
+    /// light is reflected, so this is 1.
+    #[must_use]
+    pub fn fresnel_reflectance(self, n1: f64, n2: f64) -> f64 {
+        let Some(transmitted) = self.refract(n1, n2) else {
+            return 1.0;
+        };
+
+        let cos_i = self.cos();
+        let cos_t = transmitted.cos();
+
+        let rs = (n1 * cos_i - n2 * cos_t) / (n1 * cos_i + n2 * cos_t);
+        let rp = (n1 * cos_t - n2 * cos_i) / (n1 * cos_t + n2 * cos_i);
+
+        (rs * rs + rp * rp) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Angle;
+
+    #[test]
+    fn test_refract_normal_incidence() {
+        assert_eq!(Angle::ZERO.refract(1.0, 1.5), Some(Angle::ZERO));
+        assert_eq!(Angle::ZERO.refract(1.5, 1.0), Some(Angle::ZERO));
+    }
+
+    #[test]
+    fn test_refract_air_to_water() {
+        let angle = Angle::from_degrees_exact_int(30)
+            .refract(1.0, 1.333)
+            .unwrap();
+        let expected = libm::asin(0.5 / 1.333).to_degrees();
+
+        assert!((angle.as_degrees() - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_refract_total_internal_reflection() {
+        // The critical angle from glass to air is about 41.8°
+        let glass = 1.5;
+        let air = 1.0;
+
+        assert!(Angle::from_degrees_exact_int(40)
+            .refract(glass, air)
+            .is_some());
+        assert_eq!(Angle::from_degrees_exact_int(45).refract(glass, air), None);
+        assert_eq!(Angle::from_degrees_exact_int(60).refract(glass, air), None);
+    }
+
+    #[test]
+    fn test_fresnel_normal_incidence() {
+        // At normal incidence, this is the base reflectance used by Schlick's
+        // approximation: ((n1 - n2) / (n1 + n2))^2
+        let base = |n1: f64, n2: f64| ((n1 - n2) / (n1 + n2)).powi(2);
+
+        assert!((Angle::ZERO.fresnel_reflectance(1.0, 1.5) - base(1.0, 1.5)).abs() < 1e-12);
+        assert!((Angle::ZERO.fresnel_reflectance(1.5, 1.0) - 0.04).abs() < 1e-12);
+        assert_eq!(Angle::ZERO.fresnel_reflectance(1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_fresnel_grazing_and_total_internal_reflection() {
+        let shallow = Angle::from_degrees_exact_int(60).fresnel_reflectance(1.0, 1.5);
+        let grazing = Angle::from_degrees_exact_int(89).fresnel_reflectance(1.0, 1.5);
+
+        assert!(shallow > 0.04);
+        assert!(grazing > shallow);
+        assert!(grazing < 1.0);
+
+        assert_eq!(
+            Angle::from_degrees_exact_int(60).fresnel_reflectance(1.5, 1.0),
+            1.0
+        );
+    }
+}
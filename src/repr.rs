@@ -6,7 +6,7 @@ use bitvec::{
     view::BitView,
 };
 
-use crate::sign::Sign;
+use crate::{round::RoundMode, sign::Sign};
 
 /// The value of the exponent bits equivalent to `2^0`.
 const FLOAT_ZERO_EXP: i32 = 0x03_FF;
@@ -30,43 +30,81 @@ impl<const O: i32> BaseRepr<O> {
     }
 
     /// Create a new Repr value from a float. Returns None if the float isn't
-    /// a normal finite number. Performs a modular truncation if the float is
-    /// out of range (1.5 -> 0.5, -.25 => +.75).
+    /// finite. Performs a modular truncation if the float is out of range
+    /// (1.5 -> 0.5, -.25 => +.75).
+    #[inline]
     #[must_use]
     pub fn from_float(value: f64) -> Option<Self> {
-        let value = match value.classify() {
+        Self::from_float_with(value, RoundMode::Truncate)
+    }
+
+    /// Create a new Repr value from a float, rounding bits that are too small
+    /// to be represented according to `mode`. Returns None if the float isn't
+    /// finite. As with `from_float`, out of range values are reduced modulo
+    /// `2^O`.
+    #[must_use]
+    pub fn from_float_with(value: f64, mode: RoundMode) -> Option<Self> {
+        match value.classify() {
             FpCategory::Zero => return Some(Self(0)),
-            FpCategory::Normal => value,
-            FpCategory::Nan | FpCategory::Infinite | FpCategory::Subnormal => return None,
-        };
+            FpCategory::Nan | FpCategory::Infinite => return None,
+            FpCategory::Normal | FpCategory::Subnormal => {}
+        }
 
         let float_repr = value.to_bits();
         let float_repr = float_repr.view_bits::<Lsb0>();
 
+        // Subnormals have a raw exponent of 0, but the same true exponent as
+        // the smallest normal float; they just lack the implied 1 bit.
+        let raw_exponent = float_repr[52..63].load::<u32>() as i32;
+
         // The "true" mantissa of the float, including the omitted 1 bit
         // stored in the least significant 53
         let mantissa = {
             let mut mantissa: u64 = float_repr[..52].load();
-            mantissa.view_bits_mut::<Lsb0>().set(52, true);
+            mantissa.view_bits_mut::<Lsb0>().set(52, raw_exponent != 0);
             mantissa
         };
 
         // The shift distance, based on the exponent in the float
         let shift_distance = {
-            let raw_exponent = float_repr[52..63].load::<u32>() as i32;
-            let exponent = raw_exponent - FLOAT_ZERO_EXP;
+            let exponent = raw_exponent.max(1) - FLOAT_ZERO_EXP;
             exponent + 12 - O
         };
 
-        // Perform the shift
+        let sign = Sign::from_bit(float_repr[63]);
+
+        // Perform the shift. Left shifts are always exact (any bits shifted
+        // out are whole multiples of 2^O), but right shifts may discard bits,
+        // so we need to round the magnitude based on what was discarded.
         let fixed_point_repr = if shift_distance.is_negative() {
-            mantissa >> shift_distance.abs()
-        } else {
+            // The mantissa has at most 53 bits, so for longer shifts all that
+            // matters is that some nonzero amount less than half was
+            // discarded. Clamping the distance preserves that.
+            let distance = shift_distance.unsigned_abs().min(127);
+            let mantissa = mantissa as u128;
+
+            let quotient = (mantissa >> distance) as u64;
+            let remainder = mantissa & ((1 << distance) - 1);
+            let half = 1 << (distance - 1);
+
+            let round_up = match (mode, sign) {
+                (RoundMode::Truncate, _) => false,
+                (RoundMode::Nearest, _) => {
+                    remainder > half || (remainder == half && quotient & 1 == 1)
+                }
+                (RoundMode::Ceil, Sign::Positive) | (RoundMode::Floor, Sign::Negative) => {
+                    remainder != 0
+                }
+                (RoundMode::Ceil, Sign::Negative) | (RoundMode::Floor, Sign::Positive) => false,
+            };
+
+            quotient.wrapping_add(round_up as u64)
+        } else if shift_distance < 64 {
             mantissa << shift_distance
+        } else {
+            0
         };
 
-        let sign = Sign::from_bit(float_repr[63]);
-
         // If the value is negative, perform a negation then 2's complement
         // cast. This turns out to do the right thing with regard to modular
         // arithmetic
@@ -103,6 +141,41 @@ impl<const O: i32> BaseRepr<O> {
         Self(self.0.saturating_add(rhs.0))
     }
 
+    /// Linearly interpolate from `self` to `other`, where `t` is a fraction
+    /// in the range [0, 1). This is computed exactly, in 128 bit fixed point,
+    /// as `self + (other - self) * t`. The subtraction wraps, so if `other` is
+    /// less than `self`, this interpolates "upward" through the wraparound
+    /// point; for angles, this is a counterclockwise interpolation, which is
+    /// the shorter arc whenever `other - self` is less than half a turn.
+    #[inline]
+    #[must_use]
+    pub const fn lerp(self, other: Self, t: Repr) -> Self {
+        let delta = other.0.wrapping_sub(self.0) as u128;
+        let offset = (delta * t.0 as u128) >> 64;
+
+        Self(self.0.wrapping_add(offset as u64))
+    }
+
+    /// Add a pair of `BaseRepr` values, returning `None` if the sum would
+    /// carry out of the representable range.
+    #[inline]
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(sum) => Some(Self(sum)),
+            None => None,
+        }
+    }
+
+    /// Add a pair of `BaseRepr` values, wrapping around the representable
+    /// range. Also returns whether a carry occurred.
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (sum, carry) = self.0.overflowing_add(rhs.0);
+        (Self(sum), carry)
+    }
+
     /// Convert this `Repr` value to an `f64`, retaining as much precision as
     /// possible.
     #[must_use]
@@ -114,13 +187,20 @@ impl<const O: i32> BaseRepr<O> {
         let mantissa = &view[one_idx + 1..];
         let mantissa = mantissa.get(..52).unwrap_or(mantissa);
 
+        // If there are fewer than 52 bits after the leading 1, they need to be
+        // left-aligned in the float's mantissa
+        let mantissa = match mantissa.is_empty() {
+            true => 0,
+            false => mantissa.load::<u64>() << (52 - mantissa.len()),
+        };
+
         // Safety: one_idx in in 0..64, so it surely fits in an i32
         let exponent = O - 1 - (one_idx as i32);
         let biased_exponent = FLOAT_ZERO_EXP + exponent;
 
         f64::from_bits({
             let mut float_repr: BitArray<u64, Lsb0> = BitArray::ZERO;
-            float_repr[..52].store(mantissa.load::<u64>());
+            float_repr[..52].store(mantissa);
             float_repr[52..63].store(biased_exponent);
             float_repr.load()
         })
@@ -182,6 +262,81 @@ mod build_repr_tests {
     }
 }
 
+#[cfg(test)]
+mod round_mode_tests {
+    use super::Repr;
+    use crate::round::RoundMode;
+
+    const UNIT: f64 = 1.0 / 18446744073709551616.0;
+
+    fn convert(value: f64, mode: RoundMode) -> i64 {
+        Repr::from_float_with(value * UNIT, mode).unwrap().0 as i64
+    }
+
+    #[test]
+    fn each_mode() {
+        let cases = [
+            (RoundMode::Truncate, 3, -3),
+            (RoundMode::Nearest, 4, -4),
+            (RoundMode::Ceil, 4, -3),
+            (RoundMode::Floor, 3, -4),
+        ];
+
+        for (mode, positive, negative) in cases {
+            assert_eq!(convert(3.75, mode), positive, "{mode:?}");
+            assert_eq!(convert(-3.75, mode), negative, "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn nearest_ties_to_even() {
+        assert_eq!(convert(2.5, RoundMode::Nearest), 2);
+        assert_eq!(convert(3.5, RoundMode::Nearest), 4);
+        assert_eq!(convert(-2.5, RoundMode::Nearest), -2);
+    }
+
+    #[test]
+    fn exact_values_are_unaffected() {
+        for mode in [
+            RoundMode::Truncate,
+            RoundMode::Nearest,
+            RoundMode::Ceil,
+            RoundMode::Floor,
+        ] {
+            assert_eq!(convert(3.0, mode), 3);
+            assert_eq!(Repr::from_float_with(0.75, mode), Repr::from_float(0.75));
+        }
+    }
+
+    #[test]
+    fn tiny_values() {
+        assert_eq!(Repr::from_float(1e-300), Some(Repr::new(0)));
+        assert_eq!(
+            Repr::from_float(f64::MIN_POSITIVE / 2.0),
+            Some(Repr::new(0))
+        );
+        assert_eq!(
+            Repr::from_float_with(1e-300, RoundMode::Ceil),
+            Some(Repr::new(1))
+        );
+        assert_eq!(
+            Repr::from_float_with(-1e-300, RoundMode::Floor),
+            Some(Repr::new(u64::MAX))
+        );
+        assert_eq!(
+            Repr::from_float_with(f64::MIN_POSITIVE / 2.0, RoundMode::Nearest),
+            Some(Repr::new(0))
+        );
+    }
+
+    #[test]
+    fn huge_values() {
+        assert_eq!(Repr::from_float(1e20), Some(Repr::new(0)));
+        assert_eq!(Repr::from_float(-1e300), Some(Repr::new(0)));
+        assert_eq!(Repr::from_float(f64::INFINITY), None);
+    }
+}
+
 #[cfg(test)]
 mod build_float_tests {
     use super::{BaseRepr, Repr};
@@ -213,4 +368,77 @@ mod build_float_tests {
         let float = value.as_float();
         assert_eq!(float, 2.0);
     }
+
+    #[test]
+    fn smallest() {
+        let value = Repr::new(1);
+        let float = value.as_float();
+        assert_eq!(float, 2.0f64.powi(-64));
+    }
+
+    #[test]
+    fn short_mantissa() {
+        let value = Repr::new(0b1011);
+        let float = value.as_float();
+        assert_eq!(float, 11.0 * 2.0f64.powi(-64));
+    }
+}
+
+#[cfg(test)]
+mod arithmetic_tests {
+    use super::Repr;
+
+    #[test]
+    fn checked_add() {
+        let quarter = Repr::new(0x40_00_00_00_00_00_00_00);
+        let half = Repr::new(0x80_00_00_00_00_00_00_00);
+        let three_quarters = Repr::new(0xC0_00_00_00_00_00_00_00);
+
+        assert_eq!(quarter.checked_add(half), Some(three_quarters));
+        assert_eq!(three_quarters.checked_add(three_quarters), None);
+    }
+
+    #[test]
+    fn overflowing_add() {
+        let half = Repr::new(0x80_00_00_00_00_00_00_00);
+        let three_quarters = Repr::new(0xC0_00_00_00_00_00_00_00);
+
+        assert_eq!(three_quarters.overflowing_add(three_quarters), (half, true));
+        assert_eq!(
+            half.overflowing_add(Repr::new(1)),
+            (Repr::new(0x80_00_00_00_00_00_00_01), false)
+        );
+    }
+}
+
+#[cfg(test)]
+mod lerp_tests {
+    use super::Repr;
+
+    const A: Repr = Repr::new(0x20_00_00_00_00_00_00_00);
+    const B: Repr = Repr::new(0x60_00_00_00_00_00_00_00);
+
+    #[test]
+    fn start() {
+        assert_eq!(A.lerp(B, Repr::new(0)), A);
+    }
+
+    #[test]
+    fn middle() {
+        let half = Repr::new(0x80_00_00_00_00_00_00_00);
+        assert_eq!(A.lerp(B, half), Repr::new(0x40_00_00_00_00_00_00_00));
+    }
+
+    #[test]
+    fn end() {
+        // 1 isn't representable, so this is as close to the end as we can get
+        let almost_one = Repr::new(u64::MAX);
+        assert_eq!(A.lerp(B, almost_one), Repr::new(B.0 - 1));
+    }
+
+    #[test]
+    fn wrapping() {
+        let half = Repr::new(0x80_00_00_00_00_00_00_00);
+        assert_eq!(B.lerp(A, half), Repr::new(0xC0_00_00_00_00_00_00_00));
+    }
 }
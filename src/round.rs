@@ -0,0 +1,17 @@
+/// A rounding mode, used when converting a value that can't be represented
+/// exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Round towards zero, discarding the unrepresentable bits.
+    Truncate,
+
+    /// Round to the nearest representable value, with ties rounding to
+    /// the even value.
+    Nearest,
+
+    /// Round towards positive infinity.
+    Ceil,
+
+    /// Round towards negative infinity.
+    Floor,
+}
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone, Copy)]
+/// The sign of a value, such as the result of a trig function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Sign {
     Positive,
     Negative,
@@ -0,0 +1,108 @@
+use crate::{angle::Angle, consts, repr::Repr};
+
+/// A signed angle, in the range [-0.5, 0.5) turns. This is the same set of
+/// values as [`Angle`], interpreted such that angles in the second half turn
+/// are negative. It's useful for measuring signed rotations, such as the error
+/// between a heading and its setpoint.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SignedAngle(i64);
+
+impl SignedAngle {
+    /// Create a new signed angle from the representational format. The
+    /// representation here is a fractional value in the range [-0.5, 0.5),
+    /// filling the full space of an i64.
+    #[inline]
+    #[must_use]
+    pub const fn from_repr(repr: i64) -> Self {
+        Self(repr)
+    }
+
+    /// Get a lossless representation of this angle as a signed integer.
+    #[inline]
+    #[must_use]
+    pub const fn repr(self) -> i64 {
+        self.0
+    }
+
+    /// Reinterpret an angle as a signed angle. Angles in the second half turn
+    /// become negative.
+    #[inline]
+    #[must_use]
+    pub const fn from_angle(angle: Angle) -> Self {
+        Self(angle.repr() as i64)
+    }
+
+    /// Reinterpret this signed angle as an (unsigned) angle. Negative angles
+    /// wrap around into the second half turn.
+    #[inline]
+    #[must_use]
+    pub const fn to_angle(self) -> Angle {
+        Angle::from_repr(self.0 as u64)
+    }
+
+    #[inline]
+    #[must_use]
+    fn apply_sign(self, magnitude: f64) -> f64 {
+        match self.0 < 0 {
+            true => -magnitude,
+            false => magnitude,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    fn magnitude(self) -> Repr {
+        Repr::new(self.0.unsigned_abs())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn as_rotations(self) -> f64 {
+        self.apply_sign(self.magnitude().as_float())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn as_radians(self) -> f64 {
+        self.apply_sign(consts::TAU.mul(self.magnitude()).as_float())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn as_degrees(self) -> f64 {
+        self.apply_sign(consts::DEGREES.mul(self.magnitude()).as_float())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignedAngle;
+    use crate::Angle;
+
+    #[test]
+    fn test_negative_quarter() {
+        let angle = SignedAngle::from_angle(Angle::from_repr(0xC0_00_00_00_00_00_00_00));
+
+        assert_eq!(angle.as_rotations(), -0.25);
+        assert_eq!(angle.as_degrees(), -90.0);
+        assert_eq!(angle.as_radians(), -core::f64::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn test_negative_half() {
+        let angle = SignedAngle::from_repr(i64::MIN);
+
+        assert_eq!(angle.as_degrees(), -180.0);
+        assert_eq!(
+            angle.to_angle(),
+            Angle::from_repr(0x80_00_00_00_00_00_00_00)
+        );
+    }
+
+    #[test]
+    fn test_positive() {
+        let angle = SignedAngle::from_angle(Angle::from_repr(0x40_00_00_00_00_00_00_00));
+        assert_eq!(angle.as_degrees(), 90.0);
+    }
+}
@@ -0,0 +1,383 @@
+//! Circular statistics over collections of angles.
+
+use crate::Angle;
+
+/// Sum the (weighted) unit vectors of a collection of angles. Returns the
+/// resultant vector, along with the total magnitude of the weights.
+fn resultant(samples: impl IntoIterator<Item = (Angle, f64)>) -> ([f64; 2], f64) {
+    samples
+        .into_iter()
+        .fold(([0.0, 0.0], 0.0), |([x, y], total), (angle, weight)| {
+            let [dx, dy] = angle.to_unit_vector();
+            ([x + dx * weight, y + dy * weight], total + weight.abs())
+        })
+}
+
+/// Get the direction of a resultant vector, or `None` if it's too short
+/// (relative to the total weight) to have a meaningful direction.
+fn resultant_direction([x, y]: [f64; 2], total: f64) -> Option<Angle> {
+    if libm::hypot(x, y) <= total * 1e-12 {
+        None
+    } else {
+        Angle::atan2(y, x)
+    }
+}
+
+impl Angle {
+    /// Compute the circular mean of a collection of angles: the direction
+    /// of the sum of their unit vectors. Returns `None` if there are no
+    /// angles, or if they cancel out such that there's no mean direction
+    /// (for instance, two angles a half turn apart).
+    #[must_use]
+    pub fn circular_mean(angles: &[Angle]) -> Option<Self> {
+        let (sum, total) = resultant(angles.iter().map(|&angle| (angle, 1.0)));
+        resultant_direction(sum, total)
+    }
+
+    /// Compute the mean resultant length of a collection of angles: the
+    /// length of the mean of their unit vectors. This is in the range [0, 1],
+    /// and measures how concentrated the angles are; it's 1 if they're all
+    /// identical, and near 0 if they're spread evenly around the circle.
+    /// Returns NaN if there are no angles.
+    #[must_use]
+    pub fn resultant_length(angles: &[Angle]) -> f64 {
+        let ([x, y], total) = resultant(angles.iter().map(|&angle| (angle, 1.0)));
+        libm::hypot(x, y) / total
+    }
+
+    /// Compute the weighted circular mean of a collection of angles: the
+    /// direction of the sum of their unit vectors, each scaled by the
+    /// corresponding weight. Returns `None` if the lengths of `angles` and
+    /// `weights` differ, or if there's no mean direction, as with
+    /// [`circular_mean`][Angle::circular_mean].
+    #[must_use]
+    pub fn mean_direction_weighted(angles: &[Angle], weights: &[f64]) -> Option<Self> {
+        if angles.len() != weights.len() {
+            return None;
+        }
+
+        let (sum, total) = resultant(angles.iter().copied().zip(weights.iter().copied()));
+        resultant_direction(sum, total)
+    }
+
+    /// Blend a collection of angles (such as animation targets) with the
+    /// given weights. The weights are normalized to sum to 1, and the result
+    /// is their weighted circular mean, as with
+    /// [`mean_direction_weighted`][Angle::mean_direction_weighted]. Returns
+    /// `None` if the lengths differ, if the weights don't sum to a positive
+    /// finite value, or if the blend is degenerate (such as equally blending
+    /// opposite angles).
+    #[must_use]
+    pub fn blend(angles: &[Angle], weights: &[f64]) -> Option<Self> {
+        if angles.len() != weights.len() {
+            return None;
+        }
+
+        let total: f64 = weights.iter().sum();
+
+        // Negative totals would flip the sign of every normalized weight, and
+        // this also rejects NaN
+        let valid = total > 0.0 && total.is_finite();
+        if !valid {
+            return None;
+        }
+
+        let weights = weights.iter().map(|weight| weight / total);
+        let (sum, total) = resultant(angles.iter().copied().zip(weights));
+        resultant_direction(sum, total)
+    }
+
+    /// Compute the circular median of a collection of angles: the angle that
+    /// minimizes the total shorter-arc distance to all of them. This is much
+    /// more robust to outliers than the circular mean. Only the angles
+    /// themselves are considered as candidates, and if several are equally
+    /// good, the first is returned. Returns `None` if there are no angles.
+    ///
+    /// This takes quadratic time, because it doesn't allocate.
+    #[must_use]
+    pub fn circular_median(angles: &[Angle]) -> Option<Self> {
+        angles.iter().copied().min_by_key(|&candidate| {
+            angles
+                .iter()
+                .map(|&angle| candidate.abs_diff(angle).repr() as u128)
+                .sum::<u128>()
+        })
+    }
+
+    /// Count how many times a path of directions winds counterclockwise
+    /// around the circle. This sums the shortest signed rotation between each
+    /// consecutive pair of angles, and rounds the total to a whole number of
+    /// turns; clockwise windings are negative. The path should be sampled
+    /// densely enough that consecutive angles are always less than a half
+    /// turn apart.
+    #[must_use]
+    pub fn winding_number(path: &[Angle]) -> i64 {
+        let total: i128 = path
+            .windows(2)
+            .map(|pair| pair[0].signed_distance(pair[1]).repr() as i128)
+            .sum();
+
+        ((total + (1 << 63)) >> 64) as i64
+    }
+
+    /// Find the smallest gap between adjacent angles, when a collection of
+    /// angles is sorted around the circle (including the gap that wraps
+    /// around from the last angle back to the first). Returns `None` if there
+    /// are fewer than 2 angles. Duplicate angles have a gap of zero.
+    ///
+    /// This doesn't allocate, so it can't sort the angles; instead, it takes
+    /// quadratic time to find the nearest counterclockwise neighbor of every
+    /// angle.
+    #[must_use]
+    pub fn smallest_gap(angles: &[Angle]) -> Option<Self> {
+        if angles.len() < 2 {
+            return None;
+        }
+
+        angles
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &start)| {
+                angles
+                    .iter()
+                    .enumerate()
+                    .filter(move |&(j, _)| i != j)
+                    .map(move |(_, &end)| (end - start).repr())
+            })
+            .min()
+            .map(Angle::from_repr)
+    }
+
+    /// Find the smallest arc that contains every angle in a collection,
+    /// returned as its counterclockwise `(start, span)`. This is the
+    /// complement of the largest gap between adjacent angles. Returns `None`
+    /// if there are no angles.
+    ///
+    /// As with `smallest_gap`, this takes quadratic time, because it doesn't
+    /// allocate.
+    #[must_use]
+    pub fn bounding_arc(angles: &[Angle]) -> Option<(Self, Self)> {
+        const TURN: u128 = 1 << 64;
+
+        // The gap from each angle to its nearest distinct counterclockwise
+        // neighbor. If there is no such neighbor, the gap is a full turn.
+        let (start, gap) = angles
+            .iter()
+            .map(|&start| {
+                let gap = angles
+                    .iter()
+                    .map(|&end| match (end - start).repr() {
+                        0 => TURN,
+                        gap => gap as u128,
+                    })
+                    .min()
+                    .unwrap_or(TURN);
+
+                (start, gap)
+            })
+            .max_by_key(|&(_, gap)| gap)?;
+
+        Some((
+            Angle::from_repr(start.repr().wrapping_add(gap as u64)),
+            Angle::from_repr((TURN - gap) as u64),
+        ))
+    }
+
+    /// Measure how spread out a collection of angles is, as the span of the
+    /// smallest arc containing all of them (see `bounding_arc`). Returns
+    /// `None` if there are no angles.
+    #[inline]
+    #[must_use]
+    pub fn spread(angles: &[Angle]) -> Option<Self> {
+        Self::bounding_arc(angles).map(|(_, span)| span)
+    }
+}
+
+#[cfg(test)]
+mod mean_tests {
+    use crate::Angle;
+
+    fn degrees(degrees: f64) -> Angle {
+        Angle::from_degrees(degrees).unwrap()
+    }
+
+    #[test]
+    fn test_circular_mean_wraps() {
+        let mean = Angle::circular_mean(&[degrees(350.0), degrees(10.0)]).unwrap();
+        assert!(mean.abs_diff(Angle::from_repr(0)).as_degrees() < 1e-3);
+    }
+
+    #[test]
+    fn test_circular_mean_degenerate() {
+        assert_eq!(Angle::circular_mean(&[]), None);
+        assert_eq!(Angle::circular_mean(&[degrees(0.0), degrees(180.0)]), None);
+    }
+
+    #[test]
+    fn test_resultant_length() {
+        let angles = [degrees(30.0); 5];
+        assert!((Angle::resultant_length(&angles) - 1.0).abs() < 1e-4);
+
+        let angles: [Angle; 12] = core::array::from_fn(|i| degrees(i as f64 * 30.0));
+        assert!(Angle::resultant_length(&angles) < 1e-4);
+
+        let angles = [degrees(0.0), degrees(90.0)];
+        let length = Angle::resultant_length(&angles);
+        assert!((length - core::f64::consts::FRAC_1_SQRT_2).abs() < 1e-4);
+
+        assert!(Angle::resultant_length(&[]).is_nan());
+    }
+
+    #[test]
+    fn test_circular_median() {
+        let angles = [0, 1, 2, 180].map(Angle::from_degrees_exact_int);
+        let median = Angle::circular_median(&angles).unwrap();
+
+        assert_eq!(median, Angle::from_degrees_exact_int(1));
+
+        let angles = [358, 2, 359, 90, 1].map(Angle::from_degrees_exact_int);
+        let median = Angle::circular_median(&angles).unwrap();
+
+        assert_eq!(median, Angle::from_degrees_exact_int(1));
+        assert_eq!(Angle::circular_median(&[]), None);
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let angles = [degrees(0.0), degrees(90.0)];
+
+        let mean = Angle::mean_direction_weighted(&angles, &[3.0, 1.0]).unwrap();
+        assert!(mean.as_degrees() < 45.0);
+        assert!((mean.as_degrees() - libm::atan2(1.0, 3.0).to_degrees()).abs() < 1e-3);
+
+        let mean = Angle::mean_direction_weighted(&angles, &[1.0, 1.0]).unwrap();
+        assert!((mean.as_degrees() - 45.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_blend() {
+        let angles = [degrees(0.0), degrees(90.0)];
+
+        let blend = Angle::blend(&angles, &[0.5, 0.5]).unwrap();
+        assert!((blend.as_degrees() - 45.0).abs() < 1e-3);
+
+        let blend = Angle::blend(&angles, &[2.0, 2.0]).unwrap();
+        assert!((blend.as_degrees() - 45.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_blend_degenerate() {
+        let angles = [degrees(0.0), degrees(180.0)];
+
+        assert_eq!(Angle::blend(&angles, &[1.0, 1.0]), None);
+        assert_eq!(Angle::blend(&angles, &[1.0, -1.0]), None);
+        assert_eq!(Angle::blend(&angles, &[1.0]), None);
+    }
+
+    #[test]
+    fn test_blend_invalid_total() {
+        let angles = [degrees(0.0), degrees(90.0)];
+
+        assert_eq!(Angle::blend(&angles, &[0.0, 0.0]), None);
+        assert_eq!(Angle::blend(&angles, &[1.0, -3.0]), None);
+        assert_eq!(Angle::blend(&angles, &[1.0, f64::NAN]), None);
+        assert_eq!(Angle::blend(&angles, &[1.0, f64::INFINITY]), None);
+    }
+
+    #[test]
+    fn test_weighted_mean_mismatch() {
+        let angles = [degrees(0.0), degrees(90.0)];
+        assert_eq!(Angle::mean_direction_weighted(&angles, &[1.0]), None);
+    }
+}
+
+#[cfg(test)]
+mod winding_tests {
+    use crate::Angle;
+
+    #[test]
+    fn test_two_loops() {
+        let path: [Angle; 25] =
+            core::array::from_fn(|i| Angle::from_degrees_exact_int(i as i64 * 30));
+        assert_eq!(Angle::winding_number(&path), 2);
+
+        let mut path = path;
+        path.reverse();
+        assert_eq!(Angle::winding_number(&path), -2);
+    }
+
+    #[test]
+    fn test_reversing() {
+        let path = [0, 60, 120, 170, 120, 60, 0, -60, 0].map(Angle::from_degrees_exact_int);
+        assert_eq!(Angle::winding_number(&path), 0);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert_eq!(Angle::winding_number(&[]), 0);
+        assert_eq!(Angle::winding_number(&[Angle::ZERO]), 0);
+    }
+}
+
+#[cfg(test)]
+mod gap_tests {
+    use crate::Angle;
+
+    #[test]
+    fn test_smallest_gap_even() {
+        let angles = [0, 90, 180, 270].map(Angle::from_degrees_exact_int);
+        let gap = Angle::smallest_gap(&angles).unwrap();
+
+        assert_eq!(gap, Angle::from_degrees_exact_int(90));
+    }
+
+    #[test]
+    fn test_smallest_gap_wraps() {
+        let angles = [350, 100, 5, 200].map(Angle::from_degrees_exact_int);
+        let gap = Angle::smallest_gap(&angles).unwrap();
+
+        assert!((gap.as_degrees() - 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bounding_arc() {
+        let angles = [350, 20, 0].map(Angle::from_degrees_exact_int);
+        let (start, span) = Angle::bounding_arc(&angles).unwrap();
+
+        assert_eq!(start, Angle::from_degrees_exact_int(350));
+        assert!((span.as_degrees() - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_spread() {
+        let angles = [100, 110, 130].map(Angle::from_degrees_exact_int);
+        let spread = Angle::spread(&angles).unwrap();
+        assert!((spread.as_degrees() - 30.0).abs() < 1e-9);
+
+        let angles: [Angle; 36] =
+            core::array::from_fn(|i| Angle::from_degrees_exact_int(i as i64 * 10));
+        let spread = Angle::spread(&angles).unwrap();
+        assert!((spread.as_degrees() - 350.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_spread_degenerate() {
+        assert_eq!(Angle::spread(&[]), None);
+
+        let angle = Angle::from_degrees_exact_int(45);
+        assert_eq!(
+            Angle::bounding_arc(&[angle]),
+            Some((angle, Angle::from_repr(0)))
+        );
+        assert_eq!(Angle::spread(&[angle, angle]), Some(Angle::from_repr(0)));
+    }
+
+    #[test]
+    fn test_smallest_gap_degenerate() {
+        assert_eq!(Angle::smallest_gap(&[]), None);
+        assert_eq!(Angle::smallest_gap(&[Angle::from_repr(5)]), None);
+
+        let angles = [Angle::from_repr(5), Angle::from_repr(5)];
+        assert_eq!(Angle::smallest_gap(&angles), Some(Angle::from_repr(0)));
+    }
+}
@@ -259,3 +259,102 @@ pub const CURVE: &[u64; 256] = &[
     18445355067807029948,
     18446396818965434987,
 ];
+
+/// Get read-only access to the pre-computed sine table used by the trig
+/// functions. Entry `i` is `sin(i / 1024)` rotations, as a fraction of
+/// `2^64`, for `i` in `0..256`. This is intended for self-tests, such as
+/// checksumming the table in firmware.
+#[inline]
+#[must_use]
+pub fn sine_table() -> &'static [u64] {
+    CURVE
+}
+
+/// Get the number of entries in the sine table.
+#[inline]
+#[must_use]
+pub const fn sine_table_len() -> usize {
+    CURVE.len()
+}
+
+/// Check the integrity of the sine table at runtime, such as at boot on
+/// embedded systems where the table might be corrupted by flash bit-rot.
+/// Returns `false` if the table is corrupt.
+///
+/// Only two entries correspond to angles with known exact sines: 0, and 45
+/// degrees (entry 128, which is `sqrt(2) / 2`). Notably, 30 degrees doesn't
+/// land on an entry. The rest of the table is checked against itself, using
+/// the identities `sin²(x) + cos²(x) = 1` and `sin(2x) = 2 sin(x) cos(x)`,
+/// which between them cover every entry. The entries are rounded, so the
+/// identities are checked with a tolerance of a few units; corruption of the
+/// lowest couple of bits of an entry may go undetected.
+#[inline]
+#[must_use]
+pub fn verify_sine_table() -> bool {
+    verify_table(CURVE)
+}
+
+fn verify_table(table: &[u64; 256]) -> bool {
+    // The table entries are each rounded to the nearest unit, so the
+    // identities (which involve products of several entries) are allowed a
+    // small amount of error.
+    const TOLERANCE: u64 = 4;
+
+    let sqrt_half = (1u128 << 127).isqrt() as u64;
+
+    if table[0] != 0 || table[128].abs_diff(sqrt_half) > 1 {
+        return false;
+    }
+
+    if !table.windows(2).all(|pair| pair[0] < pair[1]) {
+        return false;
+    }
+
+    // cos(x) is sin(quarter turn - x), which is table[256 - i]
+    let pythagorean = (1..256).all(|i| {
+        let sin = table[i] as u128;
+        let cos = table[256 - i] as u128;
+
+        // The sum of squares is very close to 2^128, so it wraps around to a
+        // small (possibly negative) error
+        let error = (sin * sin).wrapping_add(cos * cos) as i128;
+        error.unsigned_abs() <= (TOLERANCE as u128) << 64
+    });
+
+    let double_angle = (1..128).all(|i| {
+        let sin = table[i] as u128;
+        let cos = table[256 - i] as u128;
+        let expected = ((sin * cos) >> 63) as u64;
+
+        table[2 * i].abs_diff(expected) <= TOLERANCE
+    });
+
+    pythagorean && double_angle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sine_table, sine_table_len, verify_sine_table, verify_table, CURVE};
+
+    #[test]
+    fn test_table_shape() {
+        assert_eq!(sine_table_len(), 256);
+        assert_eq!(sine_table().len(), 256);
+        assert_eq!(sine_table()[0], 0);
+    }
+
+    #[test]
+    fn test_shipped_table_verifies() {
+        assert!(verify_sine_table());
+    }
+
+    #[test]
+    fn test_corrupt_table_fails() {
+        for (index, bit) in [(0, 0), (77, 40), (128, 3), (200, 63), (255, 10)] {
+            let mut table = *CURVE;
+            table[index] ^= 1 << bit;
+
+            assert!(!verify_table(&table), "flipped bit {bit} of entry {index}");
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! Periodic waveform synthesis, treating an angle as the phase of an
+//! oscillator.
+
+use crate::Angle;
+
+impl Angle {
+    /// Get the phase of a sinusoid with the given `frequency` (in hertz) at
+    /// the given `time` (in seconds). The phase is `frequency * time` turns,
+    /// with the whole turns discarded, so it never grows without bound.
+    /// Returns `None` if the product isn't finite.
+    #[inline]
+    #[must_use]
+    pub fn phase_at(frequency: f64, time: f64) -> Option<Self> {
+        Self::from_rotations(frequency * time)
+    }
+
+    /// Multiply the frequency of a signal with this phase by `n`, which
+    /// multiplies the phase itself by `n`. This is exact integer
+    /// multiplication of the repr, so it wraps around full turns: as with any
+    /// frequency multiplier, information about which turn the result is on is
+    /// lost (aliased).
+    #[inline]
+    #[must_use]
+    pub fn multiply_frequency(self, n: u32) -> Self {
+        self * n as u64
+    }
+
+    /// Sample a sawtooth wave at this phase, which rises linearly from -1 at
+    /// zero to (just under) 1 at the end of the turn. This is computed
+    /// directly from the repr, without the sine table.
+    #[inline]
+    #[must_use]
+    pub fn sawtooth(self) -> f64 {
+        self.as_rotations() * 2.0 - 1.0
+    }
+
+    /// Sample a triangle wave at this phase, in the range [-1, 1]. Like
+    /// `sin`, it's 0 at zero, peaks at 1 at a quarter turn, and reaches -1 at
+    /// three quarters of a turn, but it's linear in between. This is computed
+    /// directly from the repr, without the sine table.
+    #[must_use]
+    pub fn triangular_wave(self) -> f64 {
+        // Rotate the phase so that the peak is at a half turn, then measure
+        // the distance from it; this is at most a half turn.
+        let shifted = self.repr().wrapping_add(1 << 62);
+        let distance = shifted.wrapping_sub(1 << 63) as i64;
+        let distance = Angle::from_repr(distance.unsigned_abs());
+
+        1.0 - distance.as_rotations() * 4.0
+    }
+
+    /// Sample a waveform built from a sum of harmonics at this phase. This
+    /// evaluates `Σ harmonics[k] * sin((k + 1) * self)`, where each harmonic
+    /// phase is computed exactly with integer multiplication, so there's no
+    /// phase drift between the harmonics.
+    #[must_use]
+    pub fn sample_waveform(self, harmonics: &[f64]) -> f64 {
+        harmonics
+            .iter()
+            .zip(1..)
+            .map(|(&amplitude, harmonic)| amplitude * (self * harmonic).sin())
+            .sum()
+    }
+
+    /// Evaluate a sum of sinusoids at this phase, given as `(harmonic,
+    /// amplitude)` pairs: `Σ amplitude * sin(harmonic * self)`. This is the
+    /// general form of [`sample_waveform`][Angle::sample_waveform], for
+    /// additive synthesis or Fourier reconstruction with sparse harmonics.
+    /// As there, each harmonic phase is computed exactly.
+    #[must_use]
+    pub fn sin_sum_series(self, terms: &[(u32, f64)]) -> f64 {
+        terms
+            .iter()
+            .map(|&(harmonic, amplitude)| amplitude * self.multiply_frequency(harmonic).sin())
+            .sum()
+    }
+}
+
+/// A numerically controlled oscillator: a phase that advances by a fixed
+/// increment with each sample. Because the phase is accumulated with exact
+/// integer arithmetic, it never drifts, no matter how long the oscillator
+/// runs; the only error is the truncation of the increment itself, which is
+/// less than one unit of repr per sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PhaseAccumulator {
+    phase: Angle,
+    increment: Angle,
+}
+
+impl PhaseAccumulator {
+    /// Create a new oscillator at phase zero, producing a sinusoid with the
+    /// given `frequency` when sampled at `sample_rate` (both in hertz). The
+    /// increment is exactly `frequency / sample_rate` turns, computed with
+    /// [`Angle::from_ratio`], so frequencies at or above the sample rate
+    /// alias. Returns `None` if the sample rate is zero.
+    #[inline]
+    #[must_use]
+    pub const fn new(frequency: u64, sample_rate: u64) -> Option<Self> {
+        match Angle::from_ratio(frequency, sample_rate) {
+            Some(increment) => Some(Self {
+                phase: Angle::ZERO,
+                increment,
+            }),
+            None => None,
+        }
+    }
+
+    /// Get the current phase of the oscillator: the phase of the next sample.
+    #[inline]
+    #[must_use]
+    pub const fn phase(&self) -> Angle {
+        self.phase
+    }
+
+    /// Get the amount that the phase advances with each sample.
+    #[inline]
+    #[must_use]
+    pub const fn increment(&self) -> Angle {
+        self.increment
+    }
+
+    /// Get the sine of the current phase, then advance the phase by one
+    /// sample.
+    #[inline]
+    pub fn next_sample(&mut self) -> f64 {
+        let sample = self.phase.sin();
+        self.phase = self.phase + self.increment;
+        sample
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PhaseAccumulator;
+    use crate::Angle;
+
+    #[test]
+    fn test_phase_at() {
+        assert_eq!(
+            Angle::phase_at(1.0, 0.25),
+            Some(Angle::from_degrees_exact_int(90))
+        );
+        assert_eq!(Angle::phase_at(440.0, 10.5), Some(Angle::from_repr(0)));
+        assert_eq!(Angle::phase_at(f64::INFINITY, 1.0), None);
+    }
+
+    #[test]
+    fn test_multiply_frequency() {
+        let phase = Angle::from_degrees_exact_int(120);
+
+        // 120° isn't exactly representable, so it's off from a third of a
+        // turn by less than one unit, and tripling it triples that error
+        let tripled = phase.multiply_frequency(3);
+        assert!(tripled.abs_diff(Angle::from_repr(0)).repr() <= 3);
+
+        let phase = Angle::from_degrees_exact_int(90);
+        assert_eq!(
+            phase.multiply_frequency(3),
+            Angle::from_degrees_exact_int(270)
+        );
+        assert_eq!(phase.multiply_frequency(0), Angle::from_repr(0));
+    }
+
+    #[test]
+    fn test_sawtooth() {
+        assert_eq!(Angle::from_repr(0).sawtooth(), -1.0);
+        assert_eq!(Angle::from_degrees_exact_int(180).sawtooth(), 0.0);
+        assert!(Angle::from_repr(u64::MAX).sawtooth() > 1.0 - 1e-15);
+    }
+
+    #[test]
+    fn test_triangular_wave() {
+        let cases = [
+            (0, 0.0),
+            (45, 0.5),
+            (90, 1.0),
+            (180, 0.0),
+            (270, -1.0),
+            (315, -0.5),
+        ];
+
+        for (degrees, expected) in cases {
+            let angle = Angle::from_degrees_exact_int(degrees);
+            assert_eq!(angle.triangular_wave(), expected, "{degrees}°");
+        }
+
+        let wave = Angle::from_degrees_exact_int(30).triangular_wave();
+        assert!((wave - 1.0 / 3.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_fundamental_is_sin() {
+        for degrees in (0..360).step_by(15) {
+            let angle = Angle::from_degrees_exact_int(degrees);
+            assert_eq!(angle.sample_waveform(&[1.0]), angle.sin());
+        }
+    }
+
+    #[test]
+    fn test_two_harmonics() {
+        let angle = Angle::from_degrees_exact_int(30);
+        let sample = angle.sample_waveform(&[1.0, 0.5]);
+
+        // sin(30°) + 0.5 * sin(60°)
+        let expected = 0.5 + 0.5 * (3.0f64.sqrt() / 2.0);
+        assert!((sample - expected).abs() < 1e-4, "{sample} != {expected}");
+    }
+
+    #[test]
+    fn test_sin_sum_series_square() {
+        // The first three terms of the Fourier series of a square wave
+        let terms = [(1, 1.0), (3, 1.0 / 3.0), (5, 1.0 / 5.0)];
+        let cases = [
+            (90, 1.0 - 1.0 / 3.0 + 1.0 / 5.0),
+            (30, 0.5 + 1.0 / 3.0 + 0.5 / 5.0),
+            (180, 0.0),
+            (270, -(1.0 - 1.0 / 3.0 + 1.0 / 5.0)),
+        ];
+
+        for (degrees, expected) in cases {
+            let sample = Angle::from_degrees_exact_int(degrees).sin_sum_series(&terms);
+            assert!(
+                (sample - expected).abs() < 1e-4,
+                "{degrees}°: {sample} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sin_sum_series_matches_waveform() {
+        let angle = Angle::from_degrees_exact_int(50);
+        let series = angle.sin_sum_series(&[(1, 0.5), (2, 0.25)]);
+
+        assert_eq!(series, angle.sample_waveform(&[0.5, 0.25]));
+        assert_eq!(angle.sin_sum_series(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_no_harmonics() {
+        assert_eq!(Angle::from_degrees_exact_int(30).sample_waveform(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_phase_accumulator_period() {
+        let mut oscillator = PhaseAccumulator::new(1, 64).unwrap();
+
+        assert_eq!(oscillator.next_sample(), 0.0);
+        assert_eq!(oscillator.phase(), Angle::from_ratio(1, 64).unwrap());
+
+        for _ in 1..64 {
+            oscillator.next_sample();
+        }
+
+        assert_eq!(oscillator.phase(), Angle::ZERO);
+    }
+
+    #[test]
+    fn test_phase_accumulator_samples() {
+        let mut oscillator = PhaseAccumulator::new(1000, 4000).unwrap();
+        let samples: [f64; 5] = core::array::from_fn(|_| oscillator.next_sample());
+
+        assert_eq!(samples, [0.0, 1.0, 0.0, -1.0, 0.0]);
+        assert_eq!(oscillator.increment(), Angle::QUARTER_TURN);
+        assert_eq!(PhaseAccumulator::new(440, 0), None);
+    }
+}
@@ -0,0 +1,69 @@
+//! Standalone functions for reducing raw angle values into their canonical
+//! ranges, using the exact modular reduction of [`Angle`].
+
+use crate::{Angle, SignedAngle};
+
+/// Wrap a number of degrees into the range [0, 360). Non-finite inputs
+/// produce NaN.
+#[inline]
+#[must_use]
+pub fn wrap_degrees(degrees: f64) -> f64 {
+    Angle::from_degrees(degrees).map_or(f64::NAN, Angle::as_degrees)
+}
+
+/// Wrap a number of radians into the range [0, τ). Non-finite inputs produce
+/// NaN.
+#[inline]
+#[must_use]
+pub fn wrap_radians(radians: f64) -> f64 {
+    Angle::from_radians(radians).map_or(f64::NAN, Angle::as_radians)
+}
+
+/// Wrap a number of degrees into the range [-180, 180). Non-finite inputs
+/// produce NaN.
+#[inline]
+#[must_use]
+pub fn wrap_degrees_signed(degrees: f64) -> f64 {
+    Angle::from_degrees(degrees).map_or(f64::NAN, |angle| {
+        SignedAngle::from_angle(angle).as_degrees()
+    })
+}
+
+/// Wrap a number of radians into the range [-π, π). Non-finite inputs
+/// produce NaN.
+#[inline]
+#[must_use]
+pub fn wrap_radians_signed(radians: f64) -> f64 {
+    Angle::from_radians(radians).map_or(f64::NAN, |angle| {
+        SignedAngle::from_angle(angle).as_radians()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    use super::{wrap_degrees, wrap_degrees_signed, wrap_radians, wrap_radians_signed};
+
+    #[test]
+    fn test_wrap_degrees() {
+        assert_eq!(wrap_degrees(450.0), 90.0);
+        assert_eq!(wrap_degrees(-90.0), 270.0);
+        assert_eq!(wrap_degrees(45.0), 45.0);
+        assert!(wrap_degrees(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_wrap_degrees_signed() {
+        assert_eq!(wrap_degrees_signed(270.0), -90.0);
+        assert_eq!(wrap_degrees_signed(180.0), -180.0);
+        assert_eq!(wrap_degrees_signed(-450.0), -90.0);
+        assert!(wrap_degrees_signed(f64::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_wrap_radians() {
+        assert!((wrap_radians(-FRAC_PI_2) - 3.0 * FRAC_PI_2).abs() < 1e-12);
+        assert!((wrap_radians_signed(3.0 * PI) + PI).abs() < 1e-12);
+    }
+}